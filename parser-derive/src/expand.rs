@@ -0,0 +1,400 @@
+//! Generates the `ParseBytes` implementation for a derive input.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::input::*;
+use proc_macro::{Delimiter, Group, Span, TokenStream, TokenTree,};
+use std::fmt::{self, Write,};
+
+/// The number types which are read from their bytes.
+const NUMBERS: &[&str] = &["u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128", "f32", "f64",];
+
+/// The path of the `ParseBytes` trait.
+const PARSE_BYTES: &str = "::parser::parser::derive::ParseBytes";
+/// The prefix of the placeholders for user supplied tokens.
+const FRAGMENT: &str = "__parser_derive_fragment_";
+
+/// The shape of a field type.
+enum Type {
+  /// The unit type.
+  Unit,
+  /// A `bool`, read from a byte.
+  Bool,
+  /// A number, read from its bytes.
+  Number(String,),
+  /// A byte array of the given length.
+  Bytes(Vec<TokenTree>,),
+  /// A byte slice.
+  Slice,
+  /// A `Vec` of the element type.
+  Vec(Vec<TokenTree>,),
+  /// Any other type, parsed by its `ParseBytes` implementation.
+  Other,
+}
+
+impl Type {
+  /// Classifies the type `tokens`.
+  fn of(tokens: &[TokenTree],) -> Self {
+    match tokens {
+      [TokenTree::Group(group,)] if group.delimiter() == Delimiter::Parenthesis && group.stream().is_empty() => Type::Unit,
+      [TokenTree::Group(group,)] if group.delimiter() == Delimiter::Bracket => {
+        let mut array = split_at(group.stream(), ';',);
+        match (array.pop(), array.as_slice(),) {
+          (Some(len,), [element,],) if stringify(element,) == "u8" => Type::Bytes(len,),
+          _ => Type::Other,
+        }
+      },
+      [TokenTree::Ident(ident,)] if ident.to_string() == "bool" => Type::Bool,
+      [TokenTree::Ident(ident,)] if NUMBERS.contains(&&*ident.to_string(),) => Type::Number(ident.to_string(),),
+      [TokenTree::Punct(amp,), .., TokenTree::Group(group,)]
+        if amp.as_char() == '&' && group.delimiter() == Delimiter::Bracket && group.stream().to_string() == "u8" => Type::Slice,
+      _ => match tokens.iter().position(|token,| matches!(token, TokenTree::Punct(punct,) if punct.as_char() == '<'),) {
+        Some(open,) if open > 0 && tokens[open - 1].to_string() == "Vec"
+          && matches!(tokens.last(), Some(TokenTree::Punct(punct,),) if punct.as_char() == '>') => {
+          Type::Vec(tokens[open + 1..tokens.len() - 1].to_vec(),)
+        },
+        _ => Type::Other,
+      },
+    }
+  }
+}
+
+/// Generated source with placeholders for the user supplied tokens it contains.
+/// 
+/// The placeholders are replaced once the source is parsed so that errors in the user's
+/// tokens are reported at their own spans rather than at the derive.
+#[derive(Default,)]
+struct Code {
+  /// The generated source.
+  source: String,
+  /// The user supplied tokens in the order of their placeholders.
+  fragments: Vec<TokenStream>,
+}
+
+impl Code {
+  /// Appends `source`.
+  #[inline]
+  fn push_str(&mut self, source: &str,) { self.source.push_str(source,) }
+  /// Stores `tokens`, returning the placeholder which is replaced by them.
+  fn fragment(&mut self, tokens: impl IntoIterator<Item = TokenTree>,) -> String {
+    self.fragments.push(tokens.into_iter().collect(),);
+    format!("{}{}", FRAGMENT, self.fragments.len() - 1,)
+  }
+}
+
+impl Write for Code {
+  #[inline]
+  fn write_str(&mut self, source: &str,) -> fmt::Result { self.source.write_str(source,) }
+}
+
+/// Replaces the placeholders in `tokens` with their `fragments`.
+fn splice(tokens: TokenStream, fragments: &[TokenStream],) -> TokenStream {
+  tokens.into_iter().map(|token,| match token {
+    TokenTree::Ident(ident,) => match ident.to_string().strip_prefix(FRAGMENT,).and_then(|index,| index.parse::<usize>().ok(),) {
+      Some(index,) => Group::new(Delimiter::None, fragments[index].clone(),).into(),
+      None => TokenTree::Ident(ident,),
+    },
+    TokenTree::Group(group,) => {
+      let mut spliced = Group::new(group.delimiter(), splice(group.stream(), fragments,),);
+      spliced.set_span(group.span(),);
+      spliced.into()
+    },
+    token => token,
+  },).collect()
+}
+
+/// The options shared by the fields being generated.
+#[derive(Clone, Copy,)]
+struct Context<'a,> {
+  /// The lifetime of the input.
+  lifetime: &'a str,
+  /// Whether numbers are big endian unless a field says otherwise.
+  big_endian: bool,
+}
+
+impl Context<'_,> {
+  /// Applies the endianness of `attrs`.
+  #[inline]
+  fn with(self, attrs: &Attrs,) -> Self {
+    Context { big_endian: attrs.big_endian.map_or(self.big_endian, |(_, big_endian,),| big_endian,), ..self }
+  }
+}
+
+/// Expands the `ParseBytes` implementation for `item`.
+pub fn expand(item: &Item,) -> Result<TokenStream> {
+  options(&item.attrs, "a struct or enum", false,)?;
+
+  let mut lifetimes = item.params.iter().filter(|param,| param.kind == ParamKind::Lifetime,);
+  let (lifetime, mut decls,) = match (lifetimes.next(), lifetimes.next(),) {
+    (None, _,) => ("'__input", vec!["'__input".to_string()],),
+    (Some(lifetime,), None,) => (&*lifetime.name, Vec::new(),),
+    (Some(_,), Some(_,),) => return Err(Error::new(item.name.span(), "`Parse` can only be derived for types with at most one lifetime",)),
+  };
+  decls.extend(item.params.iter().map(|param,| param.decl.clone(),),);
+  let args = item.params.iter().map(|param,| param.name.as_str(),).collect::<Vec<_>>();
+  let args = if args.is_empty() { String::new() } else { format!("<{}>", args.join(", ",),) };
+  let mut predicates = item.predicates.clone();
+  for param in item.params.iter().filter(|param,| param.kind == ParamKind::Type,) {
+    write!(predicates, " {}: {}<{}>,", param.name, PARSE_BYTES, lifetime,).unwrap();
+  }
+
+  let context = Context { lifetime, big_endian: false, }.with(&item.attrs,);
+  let mut body = Code::default();
+  match &item.data {
+    Data::Struct(fields,) => {
+      steps(fields, context, &mut body,)?;
+      write!(body, "::parser::Parse::new(::parser::Output({},), __input,)", construct("Self", fields,),).unwrap();
+    },
+    Data::Enum(variants,) => choice(variants, context, &mut body,)?,
+  }
+
+  format!(
+    "impl<{decls}> {trait}<{lt}> for {name}{args} where {predicates} {{
+      #[inline]
+      fn parse_bytes(__input: &{lt} [u8],) -> ::parser::Parse<::parser::PResult<Self, &{lt} [u8],>, &{lt} [u8],> {{
+        ::parser::try_parse!(__input, |__input| {{ {body} }})
+      }}
+    }}",
+    decls = decls.join(", ",), trait = PARSE_BYTES, lt = lifetime, name = item.name, args = args,
+    predicates = predicates, body = body.source,
+  ).parse().map(|tokens,| splice(tokens, &body.fragments,),)
+    .map_err(|_,| Error::new(item.name.span(), "`Parse` generated an invalid parser",),)
+}
+
+/// Generates the choice between the variants of an enum by their tags.
+/// 
+/// The first variant whose tag matches is parsed. If no tag matches the parse is pending if
+/// any tag was a continuation of the input, otherwise the longest matched prefix is the
+/// failure.
+fn choice(variants: &[Variant], context: Context, out: &mut Code,) -> Result<()> {
+  write!(out, "let __input: &{} [u8] = __input;", context.lifetime,).unwrap();
+  if variants.is_empty() {
+    out.push_str("::parser::Parse::new(::parser::Failed(&__input[..0],), __input,)",);
+    return Ok(())
+  }
+
+  out.push_str(
+    "let mut __pending: ::core::option::Option<usize> = ::core::option::Option::None;
+    let mut __failed = &__input[..0];",
+  );
+  for variant in variants {
+    options(&variant.attrs, "a variant", true,)?;
+    let tag = match &variant.attrs.tag {
+      Some((_, tag,),) => out.fragment(tag.clone(),),
+      None => return Err(Error::new(variant.span, "each variant requires a `#[parse(tag = ..)]`",)),
+    };
+
+    write!(out,
+      "match ::parser::ParserFn::parse(&::parser::parser::sources::Tag::new({},), __input,) {{
+        ::parser::Parse {{ value: ::parser::Output(_,), unused: __input, }} => {{",
+      tag,
+    ).unwrap();
+    steps(&variant.fields, context.with(&variant.attrs,), out,)?;
+    write!(out,
+      "return ::parser::Parse::new(::parser::Output({},), __input,);
+        }},
+        ::parser::Parse {{ value: ::parser::Pending(__count,), .. }} => {{
+          __pending = ::core::option::Option::Some(__pending.map_or(__count, |__min: usize,| __min.min(__count,),),);
+        }},
+        ::parser::Parse {{ value: ::parser::Failed(__prefix,), .. }} => {{
+          if __prefix.len() > __failed.len() {{ __failed = __prefix; }}
+        }},
+      }}",
+      construct(&format!("Self::{}", variant.name,), &variant.fields,),
+    ).unwrap();
+  }
+  out.push_str(
+    "match __pending {
+      ::core::option::Option::Some(__count,) => ::parser::Parse::new(::parser::Pending(__count,), __input,),
+      ::core::option::Option::None => ::parser::Parse::new(::parser::Failed(__failed,), __input,),
+    }",
+  );
+
+  Ok(())
+}
+
+/// Generates the steps binding each of `fields` in order.
+/// 
+/// A run of consecutive `bits` fields is read from the same bytes.
+fn steps(fields: &Fields, context: Context, out: &mut Code,) -> Result<()> {
+  let fields = match fields {
+    Fields::Named(fields,) | Fields::Unnamed(fields,) => fields.as_slice(),
+    Fields::Unit => return Ok(()),
+  };
+
+  let mut index = 0;
+  while index < fields.len() {
+    if fields[index].attrs.bits.is_some() {
+      let end = fields[index..].iter().position(|field,| field.attrs.bits.is_none(),)
+        .map_or(fields.len(), |len,| index + len,);
+      bits(&fields[index..end], context, out,)?;
+      index = end;
+    } else {
+      field(&fields[index], context.with(&fields[index].attrs,), out,)?;
+      index += 1;
+    }
+  }
+
+  Ok(())
+}
+
+/// Generates the step binding a byte level field.
+fn field(field: &Field, context: Context, out: &mut Code,) -> Result<()> {
+  let lifetime = context.lifetime;
+  let binding = &field.binding;
+  match (&field.attrs.tag, &field.attrs.count,) {
+    (Some(_,), Some((span, _,),),) => Err(Error::new(*span, "`count` cannot be combined with `tag`",)),
+    (Some((_, tag,),), None,) => {
+      let tag = out.fragment(tag.clone(),);
+      let value = match Type::of(&field.ty,) {
+        Type::Unit => "()".to_string(),
+        Type::Bytes(_,) => format!("*{}", tag,),
+        _ => "::core::convert::From::from(__tag,)".to_string(),
+      };
+      write!(out,
+        "let (__tag, __input,) = ::parser::ParserFn::parse(&::parser::parser::sources::Tag::new({},), __input,)?;
+        let {} = {};",
+        tag, binding, value,
+      ).unwrap();
+      Ok(())
+    },
+    (None, Some((span, count,),),) => match Type::of(&field.ty,) {
+      Type::Slice => {
+        let count = out.fragment(count.clone(),);
+        write!(out,
+          "let ({}, __input,) = ::parser::ParserFn::parse(&::parser::parser::sources::Next::new(({}) as usize,), __input,)
+            .infallible::<&{} [u8]>()?;",
+          binding, count, lifetime,
+        ).unwrap();
+        Ok(())
+      },
+      Type::Vec(element,) => {
+        let (count, ty,) = (out.fragment(count.clone(),), out.fragment(field.ty.iter().cloned(),),);
+        write!(out,
+          "let __count = ({}) as usize;
+          let mut {} = <{}>::with_capacity(__count.min(__input.len(),),);
+          let mut __input = __input;
+          for _ in 0..__count {{",
+          count, binding, ty,
+        ).unwrap();
+        value(&element, field.span, context, "__item", "__next", out,)?;
+        write!(out, "__input = __next; {}.push(__item,); }}", binding,).unwrap();
+        Ok(())
+      },
+      _ => Err(Error::new(*span, "`count` requires a `&[u8]` or `Vec` field",)),
+    },
+    (None, None,) => value(&field.ty, field.span, context, binding, "__input", out,),
+  }
+}
+
+/// Generates the step binding a value of type `ty` to `value` and the unused input to
+/// `unused`.
+fn value(ty: &[TokenTree], span: Span, context: Context, value: &str, unused: &str, out: &mut Code,) -> Result<()> {
+  let lifetime = context.lifetime;
+  let next = |count: &str,| format!(
+    "::parser::ParserFn::parse(&::parser::parser::sources::NextN::<{{ {} }}>, __input,).infallible::<&{} [u8]>()?",
+    count, lifetime,
+  );
+  match Type::of(ty,) {
+    Type::Unit => write!(out, "let {} = (); let {} = __input;", value, unused,).unwrap(),
+    Type::Bool => write!(out, "let (&[__byte], {},) = {}; let {} = __byte != 0;", unused, next("1",), value,).unwrap(),
+    Type::Number(_,) => {
+      let number = out.fragment(ty.iter().cloned(),);
+      write!(out,
+        "let (&__bytes, {},) = {}; let {} = <{}>::from_{}_bytes(__bytes,);",
+        unused, next(&format!("::core::mem::size_of::<{}>()", number,),), value, number,
+        if context.big_endian { "be" } else { "le" },
+      ).unwrap()
+    },
+    Type::Bytes(len,) => {
+      let len = out.fragment(len,);
+      write!(out, "let (&{}, {},) = {};", value, unused, next(&len,),).unwrap()
+    },
+    Type::Slice => return Err(Error::new(span, "`&[u8]` fields require a `#[parse(count = ..)]`",)),
+    Type::Vec(_,) => return Err(Error::new(span, "`Vec` fields require a `#[parse(count = ..)]`",)),
+    Type::Other => {
+      let ty = out.fragment(ty.iter().cloned(),);
+      write!(out, "let ({}, {},) = <{} as {}<{}>>::parse_bytes(__input,)?;", value, unused, ty, PARSE_BYTES, lifetime,).unwrap()
+    },
+  }
+
+  Ok(())
+}
+
+/// Generates the step binding a run of bit level fields.
+fn bits(fields: &[Field], context: Context, out: &mut Code,) -> Result<()> {
+  let lifetime = context.lifetime;
+  let mut bindings = String::new();
+  let mut types = String::new();
+  let mut steps = String::new();
+  let mut values = String::new();
+  for field in fields {
+    if let Some((span, _,),) = field.attrs.count.as_ref().or(field.attrs.tag.as_ref(),) {
+      return Err(Error::new(*span, "`bits` cannot be combined with `count` or `tag`",))
+    }
+
+    let ty = out.fragment(field.ty.iter().cloned(),);
+    let (width, value,) = match Type::of(&field.ty,) {
+      Type::Bool => (1, format!("{} != 0", field.binding,),),
+      Type::Number(number,) if !number.starts_with('f',) => (
+        number[1..].parse::<usize>().unwrap().min(64,),
+        format!("{} as {}", field.binding, ty,),
+      ),
+      _ => return Err(Error::new(field.span, "`bits` requires an integer or `bool` field",)),
+    };
+    //The count is checked here so that the bits always fit in the field.
+    let (span, count,) = field.attrs.bits.as_ref().unwrap();
+    let count = count.parse::<usize>().map_err(|_,| Error::new(*span, "`bits` requires an integer literal",),)?;
+    if count > width {
+      return Err(Error::new(*span, format!("`bits` can be at most {} for a `{}` field", width, stringify(&field.ty,),),))
+    }
+
+    write!(steps,
+      "let ({}, __bits,) = ::parser::ParserFn::parse(&::parser::parser::sources::TakeBits::new({},), __bits,)
+        .infallible::<&{} [u8]>()?;",
+      field.binding, count, lifetime,
+    ).unwrap();
+    write!(bindings, "{}, ", field.binding,).unwrap();
+    write!(types, "{}, ", ty,).unwrap();
+    write!(values, "{}, ", value,).unwrap();
+  }
+
+  write!(out,
+    "let (({bindings}), __input,) = ::parser::ParserFnOnce::parse_once(::parser::parser::bits::InBits::new(
+      |__bits: ::parser::Bits<&{lt} [u8],>,| -> ::parser::Parse<::parser::PResult<({types}), &{lt} [u8],>, ::parser::Bits<&{lt} [u8],>,> {{
+        {steps}
+        ::parser::Parse::new(::parser::Output(({values}),), __bits,)
+      }},
+    ), __input,)?;",
+    bindings = bindings, types = types, values = values, steps = steps, lt = lifetime,
+  ).unwrap();
+
+  Ok(())
+}
+
+/// Constructs `path` from the bindings of `fields`.
+fn construct(path: &str, fields: &Fields,) -> String {
+  match fields {
+    Fields::Named(fields,) => format!(
+      "{} {{ {} }}",
+      path, fields.iter().map(|field,| format!("{},", field.binding,),).collect::<String>(),
+    ),
+    Fields::Unnamed(fields,) => format!(
+      "{}({})",
+      path, fields.iter().map(|field,| format!("{},", field.binding,),).collect::<String>(),
+    ),
+    Fields::Unit => path.to_string(),
+  }
+}
+
+/// Checks that `attrs` has no options other than the endianness and, if `tag` is
+/// allowed, a tag.
+fn options(attrs: &Attrs, what: &str, tag: bool,) -> Result<()> {
+  let tag = if tag { None } else { attrs.tag.as_ref().map(|(span, _,),| *span,) };
+  let count = attrs.count.as_ref().map(|(span, _,),| *span,);
+  match count.or(attrs.bits.as_ref().map(|(span, _,),| *span,),).or(tag,) {
+    Some(span,) => Err(Error::new(span, format!("this option cannot be applied to {}", what,),)),
+    None => Ok(()),
+  }
+}
@@ -0,0 +1,433 @@
+//! Parses the item a derive is applied to.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree,};
+
+/// An error reported at a span of the derive input.
+pub struct Error {
+  /// The span the error is reported at.
+  span: Span,
+  /// The error message.
+  message: String,
+}
+
+impl Error {
+  /// Constructs a new `Error` reporting `message` at `span`.
+  #[inline]
+  pub fn new(span: Span, message: impl Into<String>,) -> Self { Error { span, message: message.into(), } }
+  /// Expands to a `compile_error!` reporting the error.
+  pub fn into_compile_error(self,) -> TokenStream {
+    let Error { span, message, } = self;
+    let mut message = Literal::string(&message,);
+    message.set_span(span,);
+    let mut args = Group::new(Delimiter::Parenthesis, TokenTree::from(message,).into(),);
+    args.set_span(span,);
+    let mut bang = Punct::new('!', Spacing::Alone,);
+    bang.set_span(span,);
+    let mut semi = Punct::new(';', Spacing::Alone,);
+    semi.set_span(span,);
+
+    vec![
+      TokenTree::from(Ident::new("compile_error", span,),),
+      bang.into(),
+      args.into(),
+      semi.into(),
+    ].into_iter().collect()
+  }
+}
+
+/// The result of parsing the derive input.
+pub type Result<T,> = core::result::Result<T, Error,>;
+
+/// The `#[parse(..)]` options of an item, variant or field.
+/// 
+/// Each option is kept with the span of its key so misplaced options can be reported.
+#[derive(Default,)]
+pub struct Attrs {
+  /// Whether numbers are big endian, from `be` or `le`.
+  pub big_endian: Option<(Span, bool,)>,
+  /// The expression giving the number of elements, from `count = "expr"`.
+  pub count: Option<(Span, TokenStream,)>,
+  /// The literal which must be matched, from `tag = b"..."`.
+  pub tag: Option<(Span, TokenStream,)>,
+  /// The number of bits the field occupies, from `bits = n`.
+  pub bits: Option<(Span, String,)>,
+}
+
+impl Attrs {
+  /// Reads the comma separated options of a `#[parse(..)]` attribute.
+  fn options(&mut self, tokens: TokenStream,) -> Result<()> {
+    for option in split(tokens,) {
+      let mut cursor = Cursor::from(option,);
+      let key = cursor.ident()?;
+      let value = if cursor.eat_punct('=',) {
+        let value = cursor.rest();
+        if value.is_empty() { return Err(Error::new(key.span(), format!("expected a value for `{}`", key,),)) }
+        Some(value,)
+      } else { None };
+
+      match (&*key.to_string(), value,) {
+        ("be", None,) => self.big_endian = Some((key.span(), true,),),
+        ("le", None,) => self.big_endian = Some((key.span(), false,),),
+        ("count", Some(value,),) => self.count = Some((key.span(), unquote(&value,)?,),),
+        ("tag", Some(value,),) => self.tag = Some((key.span(), value.into_iter().collect(),),),
+        ("bits", Some(value,),) => self.bits = Some((key.span(), stringify(&value,),),),
+        _ => return Err(Error::new(key.span(), format!("unknown option `{}`", key,),)),
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// The kind of a generic parameter.
+#[derive(PartialEq, Eq, Clone, Copy,)]
+pub enum ParamKind {
+  /// A lifetime parameter.
+  Lifetime,
+  /// A type parameter.
+  Type,
+  /// A const parameter.
+  Const,
+}
+
+/// A generic parameter of the item.
+pub struct Param {
+  /// The kind of the parameter.
+  pub kind: ParamKind,
+  /// The name of the parameter as it appears in the generic arguments.
+  pub name: String,
+  /// The declaration of the parameter without its default.
+  pub decl: String,
+}
+
+/// A field of a struct or variant.
+pub struct Field {
+  /// The options of the field.
+  pub attrs: Attrs,
+  /// The local the field is bound to while parsing.
+  pub binding: String,
+  /// The type of the field.
+  pub ty: Vec<TokenTree>,
+  /// The span of the field.
+  pub span: Span,
+}
+
+/// The fields of a struct or variant.
+pub enum Fields {
+  /// Fields with names.
+  Named(Vec<Field>,),
+  /// Tuple fields, bound as `_0`, `_1`, etc.
+  Unnamed(Vec<Field>,),
+  /// No fields.
+  Unit,
+}
+
+/// A variant of an enum.
+pub struct Variant {
+  /// The options of the variant.
+  pub attrs: Attrs,
+  /// The name of the variant.
+  pub name: String,
+  /// The fields of the variant.
+  pub fields: Fields,
+  /// The span of the variant name.
+  pub span: Span,
+}
+
+/// The body of the item.
+pub enum Data {
+  /// The fields of a struct.
+  Struct(Fields,),
+  /// The variants of an enum.
+  Enum(Vec<Variant>,),
+}
+
+/// The item a derive is applied to.
+pub struct Item {
+  /// The options of the item.
+  pub attrs: Attrs,
+  /// The name of the item.
+  pub name: Ident,
+  /// The generic parameters of the item.
+  pub params: Vec<Param>,
+  /// The where clause predicates of the item, with a trailing comma if any are present.
+  pub predicates: String,
+  /// The body of the item.
+  pub data: Data,
+}
+
+impl Item {
+  /// Parses the derive input.
+  pub fn parse(tokens: TokenStream,) -> Result<Self> {
+    let mut cursor = Cursor::from(tokens.into_iter().collect::<Vec<_>>(),);
+    let attrs = cursor.attrs()?;
+    cursor.visibility();
+    let keyword = cursor.ident()?;
+    let name = cursor.ident()?;
+    let params = cursor.generics()?;
+    let mut predicates = cursor.predicates();
+    let data = match &*keyword.to_string() {
+      "struct" => Data::Struct(match cursor.next() {
+        Some(TokenTree::Group(group,),) if group.delimiter() == Delimiter::Brace => Fields::Named(named(group.stream(),)?,),
+        Some(TokenTree::Group(group,),) if group.delimiter() == Delimiter::Parenthesis => {
+          predicates = cursor.predicates();
+          Fields::Unnamed(unnamed(group.stream(),)?,)
+        },
+        _ => Fields::Unit,
+      },),
+      "enum" => match cursor.next() {
+        Some(TokenTree::Group(group,),) if group.delimiter() == Delimiter::Brace => Data::Enum(variants(group.stream(),)?,),
+        _ => return Err(Error::new(name.span(), "expected the variants of the enum",)),
+      },
+      _ => return Err(Error::new(keyword.span(), "`Parse` can only be derived for structs and enums",)),
+    };
+
+    Ok(Item { attrs, name, params, predicates, data, })
+  }
+}
+
+/// Parses the named fields of a struct or variant.
+fn named(tokens: TokenStream,) -> Result<Vec<Field>> {
+  split(tokens,).into_iter().map(|field,| {
+    let mut cursor = Cursor::from(field,);
+    let attrs = cursor.attrs()?;
+    cursor.visibility();
+    let name = cursor.ident()?;
+    if !cursor.eat_punct(':',) { return Err(Error::new(name.span(), "expected a field type",)) }
+
+    Ok(Field { attrs, binding: name.to_string(), ty: cursor.rest(), span: name.span(), })
+  },).collect()
+}
+
+/// Parses the tuple fields of a struct or variant.
+fn unnamed(tokens: TokenStream,) -> Result<Vec<Field>> {
+  split(tokens,).into_iter().enumerate().map(|(index, field,),| {
+    let mut cursor = Cursor::from(field,);
+    let attrs = cursor.attrs()?;
+    cursor.visibility();
+    let span = cursor.span();
+
+    Ok(Field { attrs, binding: format!("_{}", index,), ty: cursor.rest(), span, })
+  },).collect()
+}
+
+/// Parses the variants of an enum.
+fn variants(tokens: TokenStream,) -> Result<Vec<Variant>> {
+  split(tokens,).into_iter().map(|variant,| {
+    let mut cursor = Cursor::from(variant,);
+    let attrs = cursor.attrs()?;
+    let name = cursor.ident()?;
+    let fields = match cursor.next() {
+      Some(TokenTree::Group(group,),) if group.delimiter() == Delimiter::Brace => Fields::Named(named(group.stream(),)?,),
+      Some(TokenTree::Group(group,),) if group.delimiter() == Delimiter::Parenthesis => Fields::Unnamed(unnamed(group.stream(),)?,),
+      _ => Fields::Unit,
+    };
+
+    Ok(Variant { attrs, name: name.to_string(), fields, span: name.span(), })
+  },).collect()
+}
+
+/// Parses a generic parameter.
+fn param(tokens: Vec<TokenTree>,) -> Result<Param> {
+  let decl = split_at(tokens, '=',).remove(0,);
+  let (kind, name,) = match decl.as_slice() {
+    [TokenTree::Punct(quote,), TokenTree::Ident(name,), ..] if quote.as_char() == '\'' => (ParamKind::Lifetime, format!("'{}", name,),),
+    [TokenTree::Ident(keyword,), TokenTree::Ident(name,), ..] if keyword.to_string() == "const" => (ParamKind::Const, name.to_string(),),
+    [TokenTree::Ident(name,), ..] => (ParamKind::Type, name.to_string(),),
+    _ => return Err(Error::new(decl.first().map_or_else(Span::call_site, TokenTree::span,), "expected a generic parameter",)),
+  };
+
+  Ok(Param { kind, name, decl: stringify(&decl,), })
+}
+
+/// Splits `tokens` at the commas which are not nested in groups or angle brackets.
+pub fn split(tokens: impl IntoIterator<Item = TokenTree>,) -> Vec<Vec<TokenTree>> {
+  let mut items = split_at(tokens, ',',);
+  if items.last().is_some_and(Vec::is_empty,) { items.pop(); }
+  items
+}
+
+/// Splits `tokens` at each `separator` which is not nested in groups or angle brackets.
+pub fn split_at(tokens: impl IntoIterator<Item = TokenTree>, separator: char,) -> Vec<Vec<TokenTree>> {
+  let mut items = vec![Vec::new()];
+  let mut depth = 0usize;
+  let mut arrow = false;
+  for token in tokens {
+    if let TokenTree::Punct(punct,) = &token {
+      match punct.as_char() {
+        ch if ch == separator && depth == 0 => {
+          items.push(Vec::new(),);
+          arrow = false;
+          continue
+        },
+        '<' => depth += 1,
+        '>' if !arrow => depth = depth.saturating_sub(1,),
+        _ => {},
+      }
+      arrow = punct.as_char() == '-' && punct.spacing() == Spacing::Joint;
+    } else { arrow = false }
+
+    items.last_mut().unwrap().push(token,);
+  }
+
+  items
+}
+
+/// Prints `tokens` as source.
+pub fn stringify(tokens: &[TokenTree],) -> String { tokens.iter().cloned().collect::<TokenStream>().to_string() }
+
+/// Parses the contents of a string literal as tokens spanned by the literal, other tokens
+/// are returned unchanged.
+fn unquote(tokens: &[TokenTree],) -> Result<TokenStream> {
+  let source = stringify(tokens,);
+  match tokens {
+    [TokenTree::Literal(literal,)] if source.starts_with('"',) && source.ends_with('"',) => {
+      source[1..source.len() - 1].parse::<TokenStream>()
+        .map(|tokens,| respan(tokens, literal.span(),),)
+        .map_err(|_,| Error::new(literal.span(), "expected an expression",),)
+    },
+    _ => Ok(tokens.iter().cloned().collect()),
+  }
+}
+
+/// Sets the span of every token in `tokens` to `span`.
+fn respan(tokens: TokenStream, span: Span,) -> TokenStream {
+  tokens.into_iter().map(|mut token,| {
+    if let TokenTree::Group(group,) = &token {
+      token = Group::new(group.delimiter(), respan(group.stream(), span,),).into();
+    }
+    token.set_span(span,);
+    token
+  },).collect()
+}
+
+/// A position in a sequence of tokens.
+struct Cursor {
+  /// The tokens.
+  tokens: Vec<TokenTree>,
+  /// The index of the next token.
+  index: usize,
+}
+
+impl Cursor {
+  /// Returns the next token without consuming it.
+  #[inline]
+  fn peek(&self,) -> Option<&TokenTree> { self.tokens.get(self.index,) }
+  /// Consumes the next token.
+  #[inline]
+  fn next(&mut self,) -> Option<TokenTree> {
+    let token = self.tokens.get(self.index,).cloned();
+    self.index += 1;
+    token
+  }
+  /// Consumes the remaining tokens.
+  #[inline]
+  fn rest(&mut self,) -> Vec<TokenTree> {
+    let rest = self.tokens.get(self.index..,).unwrap_or_default().to_vec();
+    self.index = self.tokens.len();
+    rest
+  }
+  /// The span of the next token.
+  #[inline]
+  fn span(&self,) -> Span { self.peek().map_or_else(Span::call_site, TokenTree::span,) }
+  /// Consumes the next token if it is the punctuation `ch`.
+  fn eat_punct(&mut self, ch: char,) -> bool {
+    match self.peek() {
+      Some(TokenTree::Punct(punct,),) if punct.as_char() == ch => { self.index += 1; true },
+      _ => false,
+    }
+  }
+  /// Consumes the next token if it is the identifier `name`.
+  fn eat_ident(&mut self, name: &str,) -> bool {
+    match self.peek() {
+      Some(TokenTree::Ident(ident,),) if ident.to_string() == name => { self.index += 1; true },
+      _ => false,
+    }
+  }
+  /// Consumes an identifier.
+  fn ident(&mut self,) -> Result<Ident> {
+    match self.peek() {
+      Some(TokenTree::Ident(ident,),) => { let ident = ident.clone(); self.index += 1; Ok(ident) },
+      _ => Err(Error::new(self.span(), "expected an identifier",)),
+    }
+  }
+  /// Consumes the outer attributes, reading the options of any `#[parse(..)]`.
+  fn attrs(&mut self,) -> Result<Attrs> {
+    let mut attrs = Attrs::default();
+    while self.eat_punct('#',) {
+      let group = match self.next() {
+        Some(TokenTree::Group(group,),) if group.delimiter() == Delimiter::Bracket => group,
+        _ => return Err(Error::new(self.span(), "expected an attribute",)),
+      };
+      let mut inner = Cursor::from(group.stream().into_iter().collect::<Vec<_>>(),);
+      if !inner.eat_ident("parse",) { continue }
+
+      match inner.next() {
+        Some(TokenTree::Group(options,),) if options.delimiter() == Delimiter::Parenthesis => attrs.options(options.stream(),)?,
+        _ => return Err(Error::new(group.span(), "expected `#[parse(..)]`",)),
+      }
+    }
+
+    Ok(attrs)
+  }
+  /// Consumes a visibility.
+  fn visibility(&mut self,) {
+    if self.eat_ident("pub",) {
+      if let Some(TokenTree::Group(group,),) = self.peek() {
+        if group.delimiter() == Delimiter::Parenthesis { self.index += 1 }
+      }
+    } else { self.eat_ident("crate",); }
+  }
+  /// Consumes the generic parameters.
+  fn generics(&mut self,) -> Result<Vec<Param>> {
+    if !self.eat_punct('<',) { return Ok(Vec::new()) }
+
+    let start = self.index;
+    let mut depth = 1usize;
+    let mut arrow = false;
+    while depth > 0 {
+      match self.next() {
+        Some(TokenTree::Punct(punct,),) => {
+          match punct.as_char() {
+            '<' => depth += 1,
+            '>' if !arrow => depth -= 1,
+            _ => {},
+          }
+          arrow = punct.as_char() == '-' && punct.spacing() == Spacing::Joint;
+        },
+        Some(_,) => arrow = false,
+        None => return Err(Error::new(Span::call_site(), "unclosed generic parameters",)),
+      }
+    }
+
+    split(self.tokens[start..self.index - 1].iter().cloned(),).into_iter().map(param,).collect()
+  }
+  /// Consumes a where clause, returning its predicates.
+  fn predicates(&mut self,) -> String {
+    if !self.eat_ident("where",) { return String::new() }
+
+    let start = self.index;
+    while let Some(token,) = self.peek() {
+      match token {
+        TokenTree::Group(group,) if group.delimiter() == Delimiter::Brace => break,
+        TokenTree::Punct(punct,) if punct.as_char() == ';' => break,
+        _ => self.index += 1,
+      }
+    }
+
+    let predicates = &self.tokens[start..self.index];
+    match predicates.last() {
+      None => String::new(),
+      Some(TokenTree::Punct(punct,),) if punct.as_char() == ',' => stringify(predicates,),
+      Some(_,) => format!("{},", stringify(predicates,),),
+    }
+  }
+}
+
+impl From<Vec<TokenTree>> for Cursor {
+  #[inline]
+  fn from(tokens: Vec<TokenTree>,) -> Self { Cursor { tokens, index: 0, } }
+}
@@ -0,0 +1,92 @@
+//! Derives parsers for binary formats from struct and enum definitions.
+//! 
+//! The derive is re-exported by the `parser` crate with its `derive` feature.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+extern crate proc_macro;
+
+mod input;
+mod expand;
+
+use proc_macro::TokenStream;
+
+/// Derives `ParseBytes` for a struct or enum, parsing each field in order from a byte input.
+/// 
+/// Fields are parsed according to their type:
+/// 
+/// * Numbers are read from their bytes, little endian unless `#[parse(be)]` is given on
+///   the field or the item, `#[parse(le)]` restores little endian.
+/// * `bool` is read from a byte, any non zero byte is `true`.
+/// * `[u8; N]` is read as the next `N` bytes.
+/// * `&[u8]` and `Vec` fields require `#[parse(count = "expr")]`, `expr` may refer to the
+///   earlier fields, tuple fields are named `_0`, `_1`, etc.
+/// * Any other type is parsed by its own `ParseBytes` implementation.
+/// 
+/// Types are recognised by how they are written, not what they resolve to. An alias or a
+/// path such as `core::primitive::u16` is parsed by its `ParseBytes` implementation,
+/// always little endian and not usable with `bits`, and `count` only applies to fields
+/// written as `&[u8]` or `Vec<..>`.
+/// 
+/// `#[parse(tag = b"...")]` matches the literal instead, a `()` field discards it, a byte
+/// array field holds it and any other field is converted from the matched bytes.
+/// 
+/// `#[parse(bits = n)]` reads `n` bits into an integer or `bool` field, `n` must be an
+/// integer literal no greater than the width of the field or 64. A run of consecutive
+/// `bits` fields is read from the same bytes, the most significant bit first, any bits
+/// left over in the final byte are skipped.
+/// 
+/// The variants of an enum each require `#[parse(tag = b"...")]`, the first variant whose
+/// tag matches is parsed. If no tag matches the parse is pending if any tag continued past
+/// the end of the input, otherwise the longest matched prefix of a tag is the failure.
+/// 
+/// ```
+/// use ::parser::{*, parser::derive::ParseBytes,};
+/// 
+/// #[derive(Parse, PartialEq, Debug)]
+/// #[parse(be)]
+/// struct Header<'a> {
+///   #[parse(tag = b"\x89IMG")]
+///   magic: (),
+///   #[parse(bits = 4)]
+///   version: u8,
+///   #[parse(bits = 4)]
+///   flags: u8,
+///   width: u16,
+///   #[parse(le)]
+///   height: u16,
+///   len: u8,
+///   #[parse(count = "len")]
+///   name: &'a [u8],
+///   shape: Shape,
+/// }
+/// 
+/// #[derive(Parse, PartialEq, Debug)]
+/// enum Shape {
+///   #[parse(tag = b"C")]
+///   Circle { radius: u8 },
+///   #[parse(tag = b"R")]
+///   Rect(u8, u8),
+///   #[parse(tag = b"P")]
+///   Point,
+/// }
+/// 
+/// let input = b"\x89IMG\x21\x01\x00\x02\x00\x03abcR\x04\x05;";
+/// assert_eq!(
+///   Header::parser().parse(input),
+///   (Output(Header {
+///     magic: (), version: 2, flags: 1, width: 256, height: 2, len: 3, name: b"abc",
+///     shape: Shape::Rect(4, 5),
+///   }), &b";"[..]),
+/// );
+/// assert_eq!(Header::parse_bytes(&input[..10]), (Pending(3), &input[..10]));
+/// assert_eq!(Shape::parse_bytes(b"Q"), (Failed(&b""[..]), &b"Q"[..]));
+/// ```
+#[proc_macro_derive(Parse, attributes(parse,),)]
+pub fn derive_parse(item: TokenStream,) -> TokenStream {
+  match input::Item::parse(item,).and_then(|item,| expand::expand(&item,),) {
+    Ok(tokens,) => tokens,
+    Err(error,) => error.into_compile_error(),
+  }
+}
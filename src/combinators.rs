@@ -2,21 +2,229 @@
 //! common combinations of parsers.
 //! 
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
 use crate::{*,
   parser::{mapping::{Map, MapOk, MapErr,},
-  sequence::{Apply, ApplyOk, ApplyErr,},},
+  sequence::{Apply, ApplyOk, ApplyErr, Backtrack,},},
 };
+#[cfg(feature = "nightly",)]
+use crate::result::Failure;
+#[cfg(not(feature = "nightly",),)]
+use crate::stable::StableParse;
+#[cfg(feature = "nightly",)]
 pub use combinators_rs::*;
-use core::ops::Try;
+#[cfg(feature = "nightly",)]
+use core::ops::{Try, FromResidual, Residual, ControlFlow,};
 
 /// Pairs the outputs of both parsers in a tuple.
 pub type And<P, Q,> = Apply<Map<Pair, P,>, Q,>;
 /// Pairs the successful outputs of both parsers in a tuple.
 pub type AndOk<P, Q,> = ApplyOk<MapOk<Pair, P,>, Q,>;
-/// Pairs the failure outputs of both parsers in a tuple.
-pub type Or<P, Q,> = ApplyErr<MapErr<Pair, P,>, Q,>;
+/// Pairs the failure outputs of both parsers in a tuple, the second parser is applied to
+/// the input of the first.
+pub type Or<P, Q,> = ApplyErr<Backtrack<MapErr<Pair, P,>,>, Q,>;
+/// The `Try` type `T` with its output replaced by `U`.
+#[cfg(feature = "nightly",)]
+pub type TryOutput<T, U,> = <<T as Try>::Residual as Residual<U>>::TryType;
+/// The `Try` type `T` with its failure replaced by `E`.
+#[cfg(feature = "nightly",)]
+pub type TryFailure<T, E,> = <<<T as Try>::Residual as Failure>::Map<E,> as Residual<<T as Try>::Output>>::TryType;
+
+/// Pairs its arguments in a tuple.
+/// 
+/// It stands in for the `Pair` of `combinators_rs` in [`And`], [`AndOk`] and [`Or`] when
+/// the `nightly` feature is not enabled.
+#[cfg(not(feature = "nightly",),)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Pair;
+
+#[cfg(not(feature = "nightly",),)]
+impl<P, Q, I,> StableParse<I,> for And<P, Q,>
+  where P: StableParse<I,>,
+    Q: StableParse<I,>, {
+  type Value = (P::Value, Q::Value,);
+
+  #[inline]
+  fn parse(&self, input: I,) -> Parse<Self::Value, I,> {
+    let Parse { value: first, unused, } = self.parser.parser.parse(input,);
+    self.map.0.parse(unused,).map(|second,| (first, second,),)
+  }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<P, Q, A, B, E, I,> StableParse<I,> for AndOk<P, Q,>
+  where P: StableParse<I, Value = PResult<A, E,>,>,
+    Q: StableParse<I, Value = PResult<B, E,>,>, {
+  type Value = PResult<(A, B,), E,>;
+
+  fn parse(&self, input: I,) -> Parse<Self::Value, I,> {
+    match self.parser.parser.parse(input,) {
+      Parse { value: Output(first,), unused, } => self.map.0.parse(unused,)
+        .map(|second,| second.map(|second,| (first, second,),),),
+      Parse { value: Pending(pending,), unused, } => Parse::new(Pending(pending,), unused,),
+      Parse { value: Failed(error,), unused, } => Parse::new(Failed(error,), unused,),
+    }
+  }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<P, Q, T, E, F, I,> StableParse<I,> for Or<P, Q,>
+  where P: StableParse<I, Value = PResult<T, E,>,>,
+    Q: StableParse<I, Value = PResult<T, F,>,>,
+    I: Clone, {
+  type Value = PResult<T, (E, F,),>;
+
+  fn parse(&self, input: I,) -> Parse<Self::Value, I,> {
+    match self.parser.0.parser.parse(input.clone(),) {
+      Parse { value: Failed(first,), .. } => self.map.0.parse(input,)
+        .map(|second,| second.map_fail(|second,| (first, second,),),),
+      Parse { value: Output(value,), unused, } => Parse::new(Output(value,), unused,),
+      Parse { value: Pending(pending,), .. } => Parse::new(Pending(pending,), input,),
+    }
+  }
+}
+
+/// A function which maps the successful output of a `Try` value.
+/// 
+/// Any residual is passed through unchanged.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct TryMap<F,>(pub F,);
+
+impl<F,> TryMap<F,> {
+  /// Constructs a `TryMap` from `map`.
+  #[inline]
+  pub const fn new(map: F,) -> Self { TryMap(map,) }
+  /// Returns the inner value.
+  #[inline]
+  pub const fn into_inner(self,) -> F {
+    use core::mem::MaybeUninit;
+
+    unsafe { core::ptr::read(MaybeUninit::new(self,).as_ptr() as *const Self as *const F,) }
+  }
+}
+
+#[cfg(feature = "nightly",)]
+impl<F, T, U,> FnOnce<(T,)> for TryMap<F,>
+  where F: FnOnce(T::Output,) -> U,
+    T: Try,
+    T::Residual: Residual<U>, {
+  type Output = TryOutput<T, U,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (value,): (T,),) -> Self::Output {
+    match value.branch() {
+      ControlFlow::Continue(value,) => Try::from_output((self.0)(value,),),
+      ControlFlow::Break(residual,) => FromResidual::from_residual(residual,),
+    }
+  }
+}
+
+#[cfg(feature = "nightly",)]
+impl<F, T, U,> FnMut<(T,)> for TryMap<F,>
+  where F: FnMut(T::Output,) -> U,
+    T: Try,
+    T::Residual: Residual<U>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (value,): (T,),) -> Self::Output {
+    match value.branch() {
+      ControlFlow::Continue(value,) => Try::from_output((self.0)(value,),),
+      ControlFlow::Break(residual,) => FromResidual::from_residual(residual,),
+    }
+  }
+}
+
+#[cfg(feature = "nightly",)]
+impl<F, T, U,> Fn<(T,)> for TryMap<F,>
+  where F: Fn(T::Output,) -> U,
+    T: Try,
+    T::Residual: Residual<U>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (value,): (T,),) -> Self::Output {
+    match value.branch() {
+      ControlFlow::Continue(value,) => Try::from_output((self.0)(value,),),
+      ControlFlow::Break(residual,) => FromResidual::from_residual(residual,),
+    }
+  }
+}
+
+/// A function which maps the failure of a `Try` value.
+/// 
+/// Outputs and residuals which are not failures, such as `Pending`, are passed through
+/// unchanged.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct TryMapErr<F,>(pub F,);
+
+impl<F,> TryMapErr<F,> {
+  /// Constructs a `TryMapErr` from `map`.
+  #[inline]
+  pub const fn new(map: F,) -> Self { TryMapErr(map,) }
+  /// Returns the inner value.
+  #[inline]
+  pub const fn into_inner(self,) -> F {
+    use core::mem::MaybeUninit;
+
+    unsafe { core::ptr::read(MaybeUninit::new(self,).as_ptr() as *const Self as *const F,) }
+  }
+}
+
+#[cfg(feature = "nightly",)]
+impl<F, T, U,> FnOnce<(T,)> for TryMapErr<F,>
+  where F: FnOnce(<T::Residual as Failure>::Error,) -> U,
+    T: Try,
+    T::Residual: Failure,
+    <T::Residual as Failure>::Map<U,>: Residual<T::Output>, {
+  type Output = TryFailure<T, U,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (value,): (T,),) -> Self::Output {
+    match value.branch() {
+      ControlFlow::Continue(value,) => Try::from_output(value,),
+      ControlFlow::Break(residual,) => FromResidual::from_residual(match residual.into_failure::<U>() {
+        Ok(error) => Failure::from_failure((self.0)(error,),),
+        Err(residual) => residual,
+      },),
+    }
+  }
+}
+
+#[cfg(feature = "nightly",)]
+impl<F, T, U,> FnMut<(T,)> for TryMapErr<F,>
+  where F: FnMut(<T::Residual as Failure>::Error,) -> U,
+    T: Try,
+    T::Residual: Failure,
+    <T::Residual as Failure>::Map<U,>: Residual<T::Output>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (value,): (T,),) -> Self::Output {
+    match value.branch() {
+      ControlFlow::Continue(value,) => Try::from_output(value,),
+      ControlFlow::Break(residual,) => FromResidual::from_residual(match residual.into_failure::<U>() {
+        Ok(error) => Failure::from_failure((self.0)(error,),),
+        Err(residual) => residual,
+      },),
+    }
+  }
+}
+
+#[cfg(feature = "nightly",)]
+impl<F, T, U,> Fn<(T,)> for TryMapErr<F,>
+  where F: Fn(<T::Residual as Failure>::Error,) -> U,
+    T: Try,
+    T::Residual: Failure,
+    <T::Residual as Failure>::Map<U,>: Residual<T::Output>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (value,): (T,),) -> Self::Output {
+    match value.branch() {
+      ControlFlow::Continue(value,) => Try::from_output(value,),
+      ControlFlow::Break(residual,) => FromResidual::from_residual(match residual.into_failure::<U>() {
+        Ok(error) => Failure::from_failure((self.0)(error,),),
+        Err(residual) => residual,
+      },),
+    }
+  }
+}
 
 /// A function which applies the inner parser to the unused input before applying the
 /// function parameter to the produced output.
@@ -41,6 +249,7 @@ impl<P,> SeqApply<P,> {
     where F: FnOnce(P,) -> Q, { SeqApply(map(self.0,),) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<P, F, T, I,> FnOnce<(Parse<F, I,>,)> for SeqApply<P,>
   where P: ParserFnOnce<I,>,
     F: FnOnce(P::Value,) -> T, {
@@ -52,6 +261,7 @@ impl<P, F, T, I,> FnOnce<(Parse<F, I,>,)> for SeqApply<P,>
   }
 }
 
+#[cfg(feature = "nightly",)]
 impl<P, F, T, I,> FnMut<(Parse<F, I,>,)> for SeqApply<P,>
   where P: ParserFnMut<I,>,
     F: FnOnce(P::Value,) -> T, {
@@ -61,6 +271,7 @@ impl<P, F, T, I,> FnMut<(Parse<F, I,>,)> for SeqApply<P,>
   }
 }
 
+#[cfg(feature = "nightly",)]
 impl<P, F, T, I,> Fn<(Parse<F, I,>,)> for SeqApply<P,>
   where P: ParserFn<I,>,
     F: FnOnce(P::Value,) -> T, {
@@ -95,58 +306,64 @@ impl<P,> SeqApplyOk<P,> {
     where F: FnOnce(P,) -> Q, { SeqApplyOk(map(self.0,),) }
 }
 
-impl<P, F, G, T, U, E, I,> FnOnce<(Parse<F, I,>,)> for SeqApplyOk<P,>
+#[cfg(feature = "nightly",)]
+impl<P, F, G, T, U, I,> FnOnce<(Parse<F, I,>,)> for SeqApplyOk<P,>
   where P: ParserFnOnce<I,>,
-    F: Try<Ok = G>,
+    P::Value: Try<Output = T>,
+    <P::Value as Try>::Residual: Residual<U>,
+    F: Try<Output = G>,
     G: FnOnce(T,) -> U,
-    E: From<F::Error>,
-    P::Value: Try<Ok = T, Error = E>, {
-  type Output = Parse<Result<U, E>, I,>;
+    TryOutput<P::Value, U,>: FromResidual<F::Residual>, {
+  type Output = Parse<TryOutput<P::Value, U,>, I,>;
 
   extern "rust-call" fn call_once(self, (Parse { value, unused, },): (Parse<F, I,>,),) -> Self::Output {
-    let func = match value.into_result() {
-      Ok(v) => v,
-      Err(e) => return Parse::new(Err(e.into()), unused,),
+    let func = match value.branch() {
+      ControlFlow::Continue(func,) => func,
+      ControlFlow::Break(residual,) => return Parse::new(FromResidual::from_residual(residual,), unused,),
     };
-    match self.0.parse_once(unused,).into_result() {
-      Ok(Parse { value, unused, }) => Parse::new(Ok(func(value,)), unused,),
-      Err(parse,) => parse.map(|e,| Err(e.into()),),
+    match self.0.parse_once(unused,).branch() {
+      ControlFlow::Continue((value, unused,),) => Parse::new(Try::from_output(func(value,),), unused,),
+      ControlFlow::Break(parse,) => parse.map(FromResidual::from_residual,),
     }
   }
 }
 
-impl<P, F, G, T, U, E, I,> FnMut<(Parse<F, I,>,)> for SeqApplyOk<P,>
+#[cfg(feature = "nightly",)]
+impl<P, F, G, T, U, I,> FnMut<(Parse<F, I,>,)> for SeqApplyOk<P,>
   where P: ParserFnMut<I,>,
-    F: Try<Ok = G>,
+    P::Value: Try<Output = T>,
+    <P::Value as Try>::Residual: Residual<U>,
+    F: Try<Output = G>,
     G: FnMut(T,) -> U,
-    E: From<F::Error>,
-    P::Value: Try<Ok = T, Error = E>, {
+    TryOutput<P::Value, U,>: FromResidual<F::Residual>, {
   extern "rust-call" fn call_mut(&mut self, (Parse { value, unused, },): (Parse<F, I,>,),) -> Self::Output {
-    let mut func = match value.into_result() {
-      Ok(v) => v,
-      Err(e) => return Parse::new(Err(e.into()), unused,),
+    let mut func = match value.branch() {
+      ControlFlow::Continue(func,) => func,
+      ControlFlow::Break(residual,) => return Parse::new(FromResidual::from_residual(residual,), unused,),
     };
-    match self.0.parse_mut(unused,).into_result() {
-      Ok(Parse { value, unused, }) => Parse::new(Ok(func(value,)), unused,),
-      Err(parse,) => parse.map(|e,| Err(e.into()),),
+    match self.0.parse_mut(unused,).branch() {
+      ControlFlow::Continue((value, unused,),) => Parse::new(Try::from_output(func(value,),), unused,),
+      ControlFlow::Break(parse,) => parse.map(FromResidual::from_residual,),
     }
   }
 }
 
-impl<P, F, G, T, U, E, I,> Fn<(Parse<F, I,>,)> for SeqApplyOk<P,>
+#[cfg(feature = "nightly",)]
+impl<P, F, G, T, U, I,> Fn<(Parse<F, I,>,)> for SeqApplyOk<P,>
   where P: ParserFn<I,>,
-    F: Try<Ok = G>,
+    P::Value: Try<Output = T>,
+    <P::Value as Try>::Residual: Residual<U>,
+    F: Try<Output = G>,
     G: Fn(T,) -> U,
-    E: From<F::Error>,
-    P::Value: Try<Ok = T, Error = E>, {
+    TryOutput<P::Value, U,>: FromResidual<F::Residual>, {
   extern "rust-call" fn call(&self, (Parse { value, unused, },): (Parse<F, I,>,),) -> Self::Output {
-    let func = match value.into_result() {
-      Ok(v) => v,
-      Err(e) => return Parse::new(Err(e.into()), unused,),
+    let func = match value.branch() {
+      ControlFlow::Continue(func,) => func,
+      ControlFlow::Break(residual,) => return Parse::new(FromResidual::from_residual(residual,), unused,),
     };
-    match self.0.parse(unused,).into_result() {
-      Ok(Parse { value, unused, }) => Parse::new(Ok(func(value,)), unused,),
-      Err(parse,) => parse.map(|e,| Err(e.into()),),
+    match self.0.parse(unused,).branch() {
+      ControlFlow::Continue((value, unused,),) => Parse::new(Try::from_output(func(value,),), unused,),
+      ControlFlow::Break(parse,) => parse.map(FromResidual::from_residual,),
     }
   }
 }
@@ -176,58 +393,100 @@ impl<P,> SeqApplyErr<P,> {
     where F: FnOnce(P,) -> Q, { SeqApplyErr(map(self.0,),) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<P, F, G, T, E, U, I,> FnOnce<(Parse<F, I,>,)> for SeqApplyErr<P,>
   where P: ParserFnOnce<I,>,
-    F: Try<Error = G>,
+    P::Value: Try<Output = T>,
+    <P::Value as Try>::Residual: Failure<Error = E>,
+    <<P::Value as Try>::Residual as Failure>::Map<U,>: Residual<T>,
+    F: Try,
+    F::Residual: Failure<Error = G>,
     G: FnOnce(E,) -> U,
-    T: From<F::Ok>,
-    P::Value: Try<Ok = T, Error = E>, {
-  type Output = Parse<Result<T, U>, I,>;
+    T: From<F::Output>,
+    TryFailure<P::Value, U,>: FromResidual<<F::Residual as Failure>::Map<U,>>, {
+  type Output = Parse<TryFailure<P::Value, U,>, I,>;
 
   extern "rust-call" fn call_once(self, (Parse { value, unused, },): (Parse<F, I,>,),) -> Self::Output {
-    let func = match value.into_result() {
-      Ok(v) => return Parse::new(Ok(v.into()), unused,),
-      Err(e) => e,
+    let func = match value.branch() {
+      ControlFlow::Continue(value,) => return Parse::new(Try::from_output(value.into(),), unused,),
+      ControlFlow::Break(residual,) => match residual.into_failure::<U>() {
+        Ok(func) => func,
+        Err(residual) => return Parse::new(FromResidual::from_residual(residual,), unused,),
+      },
     };
-    match self.0.parse_once(unused,).into_result() {
-      Ok(parse,) => parse.map(|e,| Ok(e.into()),),
-      Err(Parse { value, unused, }) => Parse::new(Err(func(value,)), unused,),
+    match self.0.parse_once(unused,).branch() {
+      ControlFlow::Continue(output,) => Try::from_output(output,),
+      ControlFlow::Break(Parse { value, unused, },) => {
+        let residual = match value.into_failure::<U>() {
+          Ok(error) => Failure::from_failure(func(error,),),
+          Err(residual) => residual,
+        };
+        Parse::new(FromResidual::from_residual(residual,), unused,)
+      },
     }
   }
 }
 
+#[cfg(feature = "nightly",)]
 impl<P, F, G, T, E, U, I,> FnMut<(Parse<F, I,>,)> for SeqApplyErr<P,>
   where P: ParserFnMut<I,>,
-    F: Try<Error = G>,
+    P::Value: Try<Output = T>,
+    <P::Value as Try>::Residual: Failure<Error = E>,
+    <<P::Value as Try>::Residual as Failure>::Map<U,>: Residual<T>,
+    F: Try,
+    F::Residual: Failure<Error = G>,
     G: FnMut(E,) -> U,
-    T: From<F::Ok>,
-    P::Value: Try<Ok = T, Error = E>, {
+    T: From<F::Output>,
+    TryFailure<P::Value, U,>: FromResidual<<F::Residual as Failure>::Map<U,>>, {
   extern "rust-call" fn call_mut(&mut self, (Parse { value, unused, },): (Parse<F, I,>,),) -> Self::Output {
-    let mut func = match value.into_result() {
-      Ok(v) => return Parse::new(Ok(v.into()), unused,),
-      Err(e) => e,
+    let mut func = match value.branch() {
+      ControlFlow::Continue(value,) => return Parse::new(Try::from_output(value.into(),), unused,),
+      ControlFlow::Break(residual,) => match residual.into_failure::<U>() {
+        Ok(func) => func,
+        Err(residual) => return Parse::new(FromResidual::from_residual(residual,), unused,),
+      },
     };
-    match self.0.parse_mut(unused,).into_result() {
-      Ok(parse,) => parse.map(|e,| Ok(e.into()),),
-      Err(Parse { value, unused, }) => Parse::new(Err(func(value,)), unused,),
+    match self.0.parse_mut(unused,).branch() {
+      ControlFlow::Continue(output,) => Try::from_output(output,),
+      ControlFlow::Break(Parse { value, unused, },) => {
+        let residual = match value.into_failure::<U>() {
+          Ok(error) => Failure::from_failure(func(error,),),
+          Err(residual) => residual,
+        };
+        Parse::new(FromResidual::from_residual(residual,), unused,)
+      },
     }
   }
 }
 
+#[cfg(feature = "nightly",)]
 impl<P, F, G, T, E, U, I,> Fn<(Parse<F, I,>,)> for SeqApplyErr<P,>
   where P: ParserFn<I,>,
-    F: Try<Error = G>,
+    P::Value: Try<Output = T>,
+    <P::Value as Try>::Residual: Failure<Error = E>,
+    <<P::Value as Try>::Residual as Failure>::Map<U,>: Residual<T>,
+    F: Try,
+    F::Residual: Failure<Error = G>,
     G: Fn(E,) -> U,
-    T: From<F::Ok>,
-    P::Value: Try<Ok = T, Error = E>, {
+    T: From<F::Output>,
+    TryFailure<P::Value, U,>: FromResidual<<F::Residual as Failure>::Map<U,>>, {
   extern "rust-call" fn call(&self, (Parse { value, unused, },): (Parse<F, I,>,),) -> Self::Output {
-    let func = match value.into_result() {
-      Ok(v) => return Parse::new(Ok(v.into()), unused,),
-      Err(e) => e,
+    let func = match value.branch() {
+      ControlFlow::Continue(value,) => return Parse::new(Try::from_output(value.into(),), unused,),
+      ControlFlow::Break(residual,) => match residual.into_failure::<U>() {
+        Ok(func) => func,
+        Err(residual) => return Parse::new(FromResidual::from_residual(residual,), unused,),
+      },
     };
-    match self.0.parse(unused,).into_result() {
-      Ok(parse,) => parse.map(|e,| Ok(e.into()),),
-      Err(Parse { value, unused, }) => Parse::new(Err(func(value,)), unused,),
+    match self.0.parse(unused,).branch() {
+      ControlFlow::Continue(output,) => Try::from_output(output,),
+      ControlFlow::Break(Parse { value, unused, },) => {
+        let residual = match value.into_failure::<U>() {
+          Ok(error) => Failure::from_failure(func(error,),),
+          Err(residual) => residual,
+        };
+        Parse::new(FromResidual::from_residual(residual,), unused,)
+      },
     }
   }
 }
@@ -255,6 +514,7 @@ impl<F,> SeqPipe<F,> {
     where H: FnOnce(F,) -> G, { SeqPipe(map(self.0,),) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<F, T, U, P, I,> FnOnce<(Parse<T, I,>,)> for SeqPipe<F,>
   where F: FnOnce(T,) -> P,
     P: ParserFnOnce<I, Value = U,>, {
@@ -266,6 +526,7 @@ impl<F, T, U, P, I,> FnOnce<(Parse<T, I,>,)> for SeqPipe<F,>
   }
 }
 
+#[cfg(feature = "nightly",)]
 impl<F, T, U, P, I,> FnMut<(Parse<T, I,>,)> for SeqPipe<F,>
   where F: FnMut(T,) -> P,
     P: ParserFnOnce<I, Value = U,>, {
@@ -275,6 +536,7 @@ impl<F, T, U, P, I,> FnMut<(Parse<T, I,>,)> for SeqPipe<F,>
   }
 }
 
+#[cfg(feature = "nightly",)]
 impl<F, T, U, P, I,> Fn<(Parse<T, I,>,)> for SeqPipe<F,>
   where F: Fn(T,) -> P,
     P: ParserFnOnce<I, Value = U,>, {
@@ -307,46 +569,46 @@ impl<F,> TrySeq<F,> {
     where H: FnOnce(F,) -> G, { TrySeq(map(self.0,),) }
 }
 
-impl<F, T, P, U, E, I,> FnOnce<(Parse<T, I,>,)> for TrySeq<F,>
-  where F: FnOnce(T::Ok,) -> P,
+#[cfg(feature = "nightly",)]
+impl<F, T, P, U, I,> FnOnce<(Parse<T, I,>,)> for TrySeq<F,>
+  where F: FnOnce(T::Output,) -> P,
     T: Try,
     P: ParserFnOnce<I, Value = U,>,
-    U: Try<Error = E>,
-    E: From<T::Error>, {
+    U: FromResidual<T::Residual>, {
   type Output = Parse<U, I,>;
 
   extern "rust-call" fn call_once(self, (Parse { value, unused, },): (Parse<T, I,>,),) -> Self::Output {
-    match value.into_result() {
-      Ok(v) => (self.0)(v,)(unused,),
-      Err(e) => Parse::new(U::from_error(e.into(),), unused,),
+    match value.branch() {
+      ControlFlow::Continue(value,) => (self.0)(value,)(unused,),
+      ControlFlow::Break(residual,) => Parse::new(U::from_residual(residual,), unused,),
     }
   }
 }
 
-impl<F, T, P, U, E, I,> FnMut<(Parse<T, I,>,)> for TrySeq<F,>
-  where F: FnMut(T::Ok,) -> P,
+#[cfg(feature = "nightly",)]
+impl<F, T, P, U, I,> FnMut<(Parse<T, I,>,)> for TrySeq<F,>
+  where F: FnMut(T::Output,) -> P,
     T: Try,
     P: ParserFnOnce<I, Value = U,>,
-    U: Try<Error = E>,
-    E: From<T::Error>, {
+    U: FromResidual<T::Residual>, {
   extern "rust-call" fn call_mut(&mut self, (Parse { value, unused, },): (Parse<T, I,>,),) -> Self::Output {
-    match value.into_result() {
-      Ok(v) => (self.0)(v,)(unused,),
-      Err(e) => Parse::new(U::from_error(e.into(),), unused,),
+    match value.branch() {
+      ControlFlow::Continue(value,) => (self.0)(value,)(unused,),
+      ControlFlow::Break(residual,) => Parse::new(U::from_residual(residual,), unused,),
     }
   }
 }
 
-impl<F, T, P, U, E, I,> Fn<(Parse<T, I,>,)> for TrySeq<F,>
-  where F: Fn(T::Ok,) -> P,
+#[cfg(feature = "nightly",)]
+impl<F, T, P, U, I,> Fn<(Parse<T, I,>,)> for TrySeq<F,>
+  where F: Fn(T::Output,) -> P,
     T: Try,
     P: ParserFnOnce<I, Value = U,>,
-    U: Try<Error = E>,
-    E: From<T::Error>, {
+    U: FromResidual<T::Residual>, {
   extern "rust-call" fn call(&self, (Parse { value, unused, },): (Parse<T, I,>,),) -> Self::Output {
-    match value.into_result() {
-      Ok(v) => (self.0)(v,)(unused,),
-      Err(e) => Parse::new(U::from_error(e.into(),), unused,),
+    match value.branch() {
+      ControlFlow::Continue(value,) => (self.0)(value,)(unused,),
+      ControlFlow::Break(residual,) => Parse::new(U::from_residual(residual,), unused,),
     }
   }
 }
@@ -374,46 +636,64 @@ impl<F,> TrySeqErr<F,> {
     where H: FnOnce(F,) -> G, { TrySeqErr(map(self.0,),) }
 }
 
-impl<F, T, P, U, I,> FnOnce<(Parse<T, I,>,)> for TrySeqErr<F,>
-  where F: FnOnce(T::Error,) -> P,
+#[cfg(feature = "nightly",)]
+impl<F, T, P, U, E, I,> FnOnce<(Parse<T, I,>,)> for TrySeqErr<F,>
+  where F: FnOnce(<T::Residual as Failure>::Error,) -> P,
     T: Try,
+    T::Residual: Failure,
     P: ParserFnOnce<I, Value = U,>,
-    U: Try,
-    U::Ok: From<T::Ok>, {
+    U: Try + FromResidual<<T::Residual as Failure>::Map<E,>>,
+    U::Output: From<T::Output>,
+    U::Residual: Failure<Error = E>, {
   type Output = Parse<U, I,>;
 
   extern "rust-call" fn call_once(self, (Parse { value, unused, },): (Parse<T, I,>,),) -> Self::Output {
-    match value.into_result() {
-      Ok(v) => Parse::new(U::from_ok(v.into(),), unused,),
-      Err(e) => (self.0)(e,)(unused,),
+    match value.branch() {
+      ControlFlow::Continue(value,) => Parse::new(U::from_output(value.into(),), unused,),
+      ControlFlow::Break(residual,) => match residual.into_failure::<E>() {
+        Ok(error) => (self.0)(error,)(unused,),
+        Err(residual) => Parse::new(U::from_residual(residual,), unused,),
+      },
     }
   }
 }
 
-impl<F, T, P, U, I,> FnMut<(Parse<T, I,>,)> for TrySeqErr<F,>
-  where F: FnMut(T::Error,) -> P,
+#[cfg(feature = "nightly",)]
+impl<F, T, P, U, E, I,> FnMut<(Parse<T, I,>,)> for TrySeqErr<F,>
+  where F: FnMut(<T::Residual as Failure>::Error,) -> P,
     T: Try,
+    T::Residual: Failure,
     P: ParserFnOnce<I, Value = U,>,
-    U: Try,
-    U::Ok: From<T::Ok>, {
+    U: Try + FromResidual<<T::Residual as Failure>::Map<E,>>,
+    U::Output: From<T::Output>,
+    U::Residual: Failure<Error = E>, {
   extern "rust-call" fn call_mut(&mut self, (Parse { value, unused, },): (Parse<T, I,>,),) -> Self::Output {
-    match value.into_result() {
-      Ok(v) => Parse::new(U::from_ok(v.into(),), unused,),
-      Err(e) => (self.0)(e,)(unused,),
+    match value.branch() {
+      ControlFlow::Continue(value,) => Parse::new(U::from_output(value.into(),), unused,),
+      ControlFlow::Break(residual,) => match residual.into_failure::<E>() {
+        Ok(error) => (self.0)(error,)(unused,),
+        Err(residual) => Parse::new(U::from_residual(residual,), unused,),
+      },
     }
   }
 }
 
-impl<F, T, P, U, I,> Fn<(Parse<T, I,>,)> for TrySeqErr<F,>
-  where F: Fn(T::Error,) -> P,
+#[cfg(feature = "nightly",)]
+impl<F, T, P, U, E, I,> Fn<(Parse<T, I,>,)> for TrySeqErr<F,>
+  where F: Fn(<T::Residual as Failure>::Error,) -> P,
     T: Try,
+    T::Residual: Failure,
     P: ParserFnOnce<I, Value = U,>,
-    U: Try,
-    U::Ok: From<T::Ok>, {
+    U: Try + FromResidual<<T::Residual as Failure>::Map<E,>>,
+    U::Output: From<T::Output>,
+    U::Residual: Failure<Error = E>, {
   extern "rust-call" fn call(&self, (Parse { value, unused, },): (Parse<T, I,>,),) -> Self::Output {
-    match value.into_result() {
-      Ok(v) => Parse::new(U::from_ok(v.into(),), unused,),
-      Err(e) => (self.0)(e,)(unused,),
+    match value.branch() {
+      ControlFlow::Continue(value,) => Parse::new(U::from_output(value.into(),), unused,),
+      ControlFlow::Break(residual,) => match residual.into_failure::<E>() {
+        Ok(error) => (self.0)(error,)(unused,),
+        Err(residual) => Parse::new(U::from_residual(residual,), unused,),
+      },
     }
   }
 }
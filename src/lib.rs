@@ -1,32 +1,32 @@
 //! A library of parser combinators.
 //! 
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-03-31
+//! Last Moddified --- 2026-10-18
 
 #![no_std]
 #![deny(missing_docs,)]
-#![feature(
-  never_type, coerce_unsized, try_trait, const_ptr_read, const_maybe_uninit_as_ptr,
-  const_refs_to_cell, allocator_api, external_doc, bool_to_option, array_from_ref,
-  unboxed_closures, const_fn, const_mut_refs, fn_traits, const_fn_fn_ptr_basics,
-  const_fn_transmute, const_raw_ptr_deref, const_panic, min_type_alias_impl_trait,
-)]
+#![cfg_attr(feature = "nightly", feature(never_type, try_trait_v2, try_trait_v2_residual, unboxed_closures, fn_traits,),)]
 
 #[cfg(feature = "alloc",)]
 extern crate alloc;
-#[cfg(any(test, doctest,),)]
+#[cfg(any(test, doctest, feature = "std",),)]
 #[macro_use]
 extern crate std;
 
 pub mod result;
+#[cfg(any(feature = "nightly", feature = "stable",),)]
 pub mod parser;
+#[cfg(any(feature = "nightly", feature = "stable",),)]
 pub mod combinators;
+#[cfg(feature = "stable",)]
+pub mod stable;
 
-pub use self::{
-  result::{Parse, PResult::{self, *,},},
-  parser::{Parser, ParserFnOnce, ParserFnMut, ParserFn,},
-};
+pub use self::result::{Parse, PResult::{self, *,},};
+#[cfg(feature = "nightly",)]
+pub use self::parser::{Parser, ParserFnOnce, ParserFnMut, ParserFn, bits::Bits, state::Stateful,};
+#[cfg(feature = "derive",)]
+pub use parser_derive::Parse;
 
-#[cfg(doctest,)]
-#[doc(include="../README.md",)]
+#[cfg(all(doctest, feature = "nightly",),)]
+#[doc = include_str!("../README.md",)]
 struct DoctestReadme;
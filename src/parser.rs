@@ -1,12 +1,48 @@
 //! Defines the [`Parser`](self::Parser) struct and `ParserFn*` traits.
 //! 
+//! Without the `nightly` feature only the sources and combinators shared with the
+//! `stable` module are available.
+//! 
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
+#[cfg(feature = "nightly",)]
 mod parser_fn;
 pub mod sources;
 pub mod mapping;
 pub mod sequence;
+#[cfg(feature = "nightly",)]
+pub mod bits;
+#[cfg(feature = "nightly",)]
+pub mod framing;
+#[cfg(feature = "nightly",)]
+pub mod lexeme;
+#[cfg(feature = "nightly",)]
+pub mod pratt;
+#[cfg(feature = "nightly",)]
+pub mod state;
+#[cfg(feature = "nightly",)]
+pub mod indent;
+#[cfg(feature = "nightly",)]
+pub mod lexer;
+#[cfg(feature = "nightly",)]
+pub mod observe;
+#[cfg(feature = "nightly",)]
+pub mod iter;
+#[cfg(feature = "nightly",)]
+pub mod repeat;
+#[cfg(feature = "nightly",)]
+pub mod grammar;
+#[cfg(feature = "nightly",)]
+pub mod derive;
+#[cfg(all(feature = "nightly", any(feature = "std", feature = "log",),),)]
+pub mod trace;
+#[cfg(all(feature = "nightly", feature = "alloc",),)]
+pub mod memo;
+#[cfg(all(feature = "nightly", feature = "alloc",),)]
+pub mod left_rec;
+#[cfg(feature = "nightly",)]
 mod parser;
 
+#[cfg(feature = "nightly",)]
 pub use self::{parser::*, parser_fn::*,};
@@ -0,0 +1,186 @@
+//! Defines the [`Bits`](self::Bits) input and the adaptors for moving between bit level and
+//! byte level parsers.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+
+/// An input adaptor which tracks a bit offset into a sequence of bytes.
+/// 
+/// Bits are read from the most significant bit of each byte first.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Bits<I,> {
+  /// The remaining bytes.
+  pub input: I,
+  /// The number of bits already consumed from the front of the bytes.
+  /// 
+  /// Parsers leave the offset below 8, larger offsets skip whole bytes.
+  pub offset: usize,
+}
+
+impl<I,> Bits<I,> {
+  /// Constructs a new `Bits` starting at the first bit of `input`.
+  #[inline]
+  pub const fn new(input: I,) -> Self { Bits { input, offset: 0, } }
+}
+
+impl<'a,> Bits<&'a [u8],> {
+  /// The number of bits remaining.
+  #[inline]
+  pub const fn len(&self,) -> usize { (self.input.len() * 8).saturating_sub(self.offset,) }
+  /// Checks if there are no bits remaining.
+  #[inline]
+  pub const fn is_empty(&self,) -> bool { self.len() == 0 }
+  /// Checks if the next bit is the first bit of a byte.
+  #[inline]
+  pub const fn is_aligned(&self,) -> bool { self.offset % 8 == 0 }
+  /// Returns the remaining bytes, skipping any bits left in a partially consumed byte.
+  #[inline]
+  pub fn into_bytes(self,) -> &'a [u8] { self.input.get((self.offset + 7) / 8..,).unwrap_or(&[],) }
+  /// Splits `count` bits from the front of the input.
+  /// 
+  /// `count` must be no greater than 64 or the number of bits remaining.
+  pub(crate) fn split_bits(self, count: usize,) -> (u64, Self,) {
+    let Bits { mut input, mut offset, } = self;
+    input = input.get(offset / 8..,).unwrap_or(&[],);
+    offset %= 8;
+    let mut value = 0u64;
+    let mut remaining = count;
+    while remaining > 0 {
+      let available = 8 - offset;
+      let take = remaining.min(available,);
+      let bits = (input[0] as u64 >> (available - take)) & ((1 << take) - 1);
+
+      value = value << take | bits;
+      remaining -= take;
+      offset += take;
+      if offset == 8 {
+        offset = 0;
+        input = &input[1..];
+      }
+    }
+
+    (value, Bits { input, offset, },)
+  }
+  /// The number of bytes which must be appended to the input before `count` bits are
+  /// available.
+  #[inline]
+  pub(crate) const fn pending(&self, count: usize,) -> usize {
+    (count.saturating_sub(self.len(),) + 7) / 8
+  }
+}
+
+impl<I,> From<I> for Bits<I,> {
+  #[inline]
+  fn from(from: I,) -> Self { Bits::new(from,) }
+}
+
+/// A parser which applies a bit level parser to a byte input.
+/// 
+/// Any bits left in a partially consumed byte are skipped.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct InBits<P,>(pub P,);
+
+impl<P,> InBits<P,> {
+  /// Constructs a new `InBits` from `parser`.
+  #[inline]
+  pub const fn new(parser: P,) -> Self { InBits(parser,) }
+  /// Returns the inner value.
+  #[inline]
+  pub const fn into_inner(self,) -> P {
+    use core::mem::MaybeUninit;
+
+    unsafe { core::ptr::read(MaybeUninit::new(self,).as_ptr() as *const Self as *const P,) }
+  }
+}
+
+impl<'a, P,> FnOnce<(&'a [u8],),> for InBits<P,>
+  where P: ParserFnOnce<Bits<&'a [u8],>,>, {
+  type Output = Parse<P::Value, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output {
+    let Parse { value, unused, } = self.0.parse_once(Bits::new(input,),);
+    Parse::new(value, unused.into_bytes(),)
+  }
+}
+
+impl<'a, P,> FnMut<(&'a [u8],),> for InBits<P,>
+  where P: ParserFnMut<Bits<&'a [u8],>,>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output {
+    let Parse { value, unused, } = self.0.parse_mut(Bits::new(input,),);
+    Parse::new(value, unused.into_bytes(),)
+  }
+}
+
+impl<'a, P,> Fn<(&'a [u8],),> for InBits<P,>
+  where P: ParserFn<Bits<&'a [u8],>,>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output {
+    let Parse { value, unused, } = self.0.parse(Bits::new(input,),);
+    Parse::new(value, unused.into_bytes(),)
+  }
+}
+
+/// A parser which applies a byte level parser to a bit input.
+/// 
+/// Any bits left in a partially consumed byte are skipped before the parser is applied,
+/// if the parser fails or is pending the bit input is returned unchanged.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct InBytes<P,>(pub P,);
+
+impl<P,> InBytes<P,> {
+  /// Constructs a new `InBytes` from `parser`.
+  #[inline]
+  pub const fn new(parser: P,) -> Self { InBytes(parser,) }
+  /// Returns the inner value.
+  #[inline]
+  pub const fn into_inner(self,) -> P {
+    use core::mem::MaybeUninit;
+
+    unsafe { core::ptr::read(MaybeUninit::new(self,).as_ptr() as *const Self as *const P,) }
+  }
+}
+
+impl<'a, P, T, E,> FnOnce<(Bits<&'a [u8],>,),> for InBytes<P,>
+  where P: ParserFnOnce<&'a [u8], Value = PResult<T, E,>,>, {
+  type Output = Parse<PResult<T, E,>, Bits<&'a [u8],>,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (Bits<&'a [u8],>,),) -> Self::Output {
+    in_bytes(self.0.parse_once(input.into_bytes(),), input,)
+  }
+}
+
+impl<'a, P, T, E,> FnMut<(Bits<&'a [u8],>,),> for InBytes<P,>
+  where P: ParserFnMut<&'a [u8], Value = PResult<T, E,>,>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (Bits<&'a [u8],>,),) -> Self::Output {
+    in_bytes(self.0.parse_mut(input.into_bytes(),), input,)
+  }
+}
+
+impl<'a, P, T, E,> Fn<(Bits<&'a [u8],>,),> for InBytes<P,>
+  where P: ParserFn<&'a [u8], Value = PResult<T, E,>,>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (Bits<&'a [u8],>,),) -> Self::Output {
+    in_bytes(self.0.parse(input.into_bytes(),), input,)
+  }
+}
+
+/// Returns the bit input unchanged unless the byte level parse produced an output.
+#[inline]
+fn in_bytes<'a, T, E,>(
+  parse: Parse<PResult<T, E,>, &'a [u8],>,
+  input: Bits<&'a [u8],>,
+) -> Parse<PResult<T, E,>, Bits<&'a [u8],>,> {
+  let Parse { value, unused, } = parse;
+  match value {
+    Output(value,) => Parse::new(Output(value,), Bits::new(unused,),),
+    value => Parse::new(value, input,),
+  }
+}
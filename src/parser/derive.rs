@@ -0,0 +1,73 @@
+//! Defines the [`ParseBytes`](self::ParseBytes) trait implemented by `#[derive(Parse)]`.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{*, parser::sources::NextN,};
+
+/// A type which can be parsed from the front of a byte input.
+/// 
+/// It is implemented for numbers, read little endian, `bool` and byte arrays. With the
+/// `derive` feature it is implemented for structs and enums by `#[derive(Parse)]`, the
+/// failure of the parse is the tag prefix which was matched before an unexpected byte.
+/// 
+/// ```
+/// use ::parser::{*, parser::{derive::ParseBytes, sources::{NextN, Tag,},},};
+/// 
+/// #[derive(PartialEq, Debug)]
+/// struct Chunk { kind: u8, len: u16, }
+/// 
+/// impl<'a> ParseBytes<'a> for Chunk {
+///   fn parse_bytes(input: &'a [u8]) -> Parse<PResult<Self, &'a [u8]>, &'a [u8]> {
+///     try_parse!(input, |unused| {
+///       let (_, unused) = Tag::new(b"CK").parse(unused)?;
+///       let (&[kind], unused) = NextN::<1>.parse(unused).infallible::<&[u8]>()?;
+///       let (&len, unused) = NextN::<2>.parse(unused).infallible::<&[u8]>()?;
+///       Parse::new(Output(Chunk { kind, len: u16::from_le_bytes(len) }), unused)
+///     })
+///   }
+/// }
+/// 
+/// let parser = Chunk::parser();
+/// assert_eq!(parser.parse(b"CK\x02\x05\x00;"), (Output(Chunk { kind: 2, len: 5 }), &b";"[..]));
+/// assert_eq!(parser.parse(b"CK\x02"), (Pending(2), &b"CK\x02"[..]));
+/// assert_eq!(parser.parse(b"CX"), (Failed(&b"C"[..]), &b"CX"[..]));
+/// ```
+pub trait ParseBytes<'a,>: Sized {
+  /// Parses a value from the front of `input`.
+  fn parse_bytes(input: &'a [u8],) -> Parse<PResult<Self, &'a [u8],>, &'a [u8],>;
+  /// Returns a `Parser` for the type.
+  #[inline]
+  fn parser() -> Parser<fn(&'a [u8],) -> Parse<PResult<Self, &'a [u8],>, &'a [u8],>,> {
+    Parser::new(Self::parse_bytes,)
+  }
+}
+
+/// Implements `ParseBytes` for numbers, read little endian.
+macro_rules! impl_numbers {
+  ($($number:ty,)*) => {$(
+    impl<'a,> ParseBytes<'a,> for $number {
+      #[inline]
+      fn parse_bytes(input: &'a [u8],) -> Parse<PResult<Self, &'a [u8],>, &'a [u8],> {
+        NextN::<{ core::mem::size_of::<$number>() }>.parse(input,).infallible()
+          .map(|value,| value.map(|&bytes,| <$number>::from_le_bytes(bytes,),),)
+      }
+    }
+  )*};
+}
+
+impl_numbers!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64,);
+
+impl<'a,> ParseBytes<'a,> for bool {
+  #[inline]
+  fn parse_bytes(input: &'a [u8],) -> Parse<PResult<Self, &'a [u8],>, &'a [u8],> {
+    NextN::<1>.parse(input,).infallible().map(|value,| value.map(|&[byte],| byte != 0,),)
+  }
+}
+
+impl<'a, const N: usize,> ParseBytes<'a,> for [u8; N] {
+  #[inline]
+  fn parse_bytes(input: &'a [u8],) -> Parse<PResult<Self, &'a [u8],>, &'a [u8],> {
+    NextN::<N>.parse(input,).infallible().map(|value,| value.map(|&bytes,| bytes,),)
+  }
+}
@@ -0,0 +1,170 @@
+//! Parsers for length prefixed frames of input.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+use core::convert::TryFrom;
+
+/// The error produced by the length prefixed parsers.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum LengthError<E, F = !, I = !,> {
+  /// The length parser failed.
+  Length(E,),
+  /// The length could not be represented as a `usize`.
+  Overflow,
+  /// The value parser failed.
+  Value(F,),
+  /// The value parser required more tokens than the frame held.
+  Incomplete(usize,),
+  /// The value parser did not consume the whole frame, the unused tokens are returned.
+  Unused(I,),
+}
+
+/// A parser which reads a length and then accepts that many tokens.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct LengthData<P,>(pub P,);
+
+impl<P,> LengthData<P,> {
+  /// Constructs a new `LengthData` from the `length` parser.
+  #[inline]
+  pub const fn new(length: P,) -> Self { LengthData(length,) }
+  /// Returns the inner value.
+  #[inline]
+  pub const fn into_inner(self,) -> P {
+    use core::mem::MaybeUninit;
+
+    unsafe { core::ptr::read(MaybeUninit::new(self,).as_ptr() as *const Self as *const P,) }
+  }
+}
+
+impl<'a, P, L, E, I,> FnOnce<(&'a [I],),> for LengthData<P,>
+  where P: ParserFnOnce<&'a [I], Value = PResult<L, E,>,>,
+    usize: TryFrom<L>, {
+  type Output = Parse<PResult<&'a [I], LengthError<E,>,>, &'a [I],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [I],),) -> Self::Output {
+    length_data(self.0.parse_once(input,), input,)
+  }
+}
+
+impl<'a, P, L, E, I,> FnMut<(&'a [I],),> for LengthData<P,>
+  where P: ParserFnMut<&'a [I], Value = PResult<L, E,>,>,
+    usize: TryFrom<L>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output {
+    length_data(self.0.parse_mut(input,), input,)
+  }
+}
+
+impl<'a, P, L, E, I,> Fn<(&'a [I],),> for LengthData<P,>
+  where P: ParserFn<&'a [I], Value = PResult<L, E,>,>,
+    usize: TryFrom<L>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output {
+    length_data(self.0.parse(input,), input,)
+  }
+}
+
+/// A parser which reads a length and then applies a parser to exactly that many tokens.
+/// 
+/// If the value parser does not consume the whole frame the unused tokens are returned as
+/// the error.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct LengthValue<P, Q,> {
+  /// The parser for the length.
+  pub length: P,
+  /// The parser for the value.
+  pub value: Q,
+}
+
+impl<P, Q,> LengthValue<P, Q,> {
+  /// Constructs a new `LengthValue` from the `length` and `value` parsers.
+  #[inline]
+  pub const fn new(length: P, value: Q,) -> Self { LengthValue { length, value, } }
+}
+
+impl<'a, P, Q, L, E, T, F, I,> FnOnce<(&'a [I],),> for LengthValue<P, Q,>
+  where P: ParserFnOnce<&'a [I], Value = PResult<L, E,>,>,
+    Q: ParserFnOnce<&'a [I], Value = PResult<T, F,>,>,
+    usize: TryFrom<L>, {
+  type Output = Parse<PResult<T, LengthError<E, F, &'a [I],>,>, &'a [I],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [I],),) -> Self::Output {
+    let value = self.value;
+    length_value(length_data(self.length.parse_once(input,), input,), input, |frame,| value.parse_once(frame,),)
+  }
+}
+
+impl<'a, P, Q, L, E, T, F, I,> FnMut<(&'a [I],),> for LengthValue<P, Q,>
+  where P: ParserFnMut<&'a [I], Value = PResult<L, E,>,>,
+    Q: ParserFnMut<&'a [I], Value = PResult<T, F,>,>,
+    usize: TryFrom<L>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output {
+    let value = &mut self.value;
+    length_value(length_data(self.length.parse_mut(input,), input,), input, |frame,| value.parse_mut(frame,),)
+  }
+}
+
+impl<'a, P, Q, L, E, T, F, I,> Fn<(&'a [I],),> for LengthValue<P, Q,>
+  where P: ParserFn<&'a [I], Value = PResult<L, E,>,>,
+    Q: ParserFn<&'a [I], Value = PResult<T, F,>,>,
+    usize: TryFrom<L>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output {
+    length_value(length_data(self.length.parse(input,), input,), input, |frame,| self.value.parse(frame,),)
+  }
+}
+
+/// Splits the frame described by the parsed length from the front of the unused input.
+fn length_data<'a, L, E, I,>(
+  Parse { value, unused, }: Parse<PResult<L, E,>, &'a [I],>,
+  input: &'a [I],
+) -> Parse<PResult<&'a [I], LengthError<E,>,>, &'a [I],>
+  where usize: TryFrom<L>, {
+  let len = match value {
+    Output(len,) => match usize::try_from(len,) {
+      Ok(len) => len,
+      Err(_) => return Parse::new(Failed(LengthError::Overflow,), input,),
+    },
+    Pending(pending,) => return Parse::new(Pending(pending,), input,),
+    Failed(error,) => return Parse::new(Failed(LengthError::Length(error,),), input,),
+  };
+
+  match len.checked_sub(unused.len(),) {
+    Some(pending) if pending > 0 => Parse::new(Pending(pending,), input,),
+    _ => Parse::from(unused.split_at(len,),).map(Output,),
+  }
+}
+
+/// Applies `parser` to the frame produced by `length_data`.
+fn length_value<'a, T, E, F, I, Q,>(
+  Parse { value, unused, }: Parse<PResult<&'a [I], LengthError<E,>,>, &'a [I],>,
+  input: &'a [I],
+  parser: Q,
+) -> Parse<PResult<T, LengthError<E, F, &'a [I],>,>, &'a [I],>
+  where Q: FnOnce(&'a [I],) -> Parse<PResult<T, F,>, &'a [I],>, {
+  let frame = match value {
+    Output(frame,) => frame,
+    Pending(pending,) => return Parse::new(Pending(pending,), input,),
+    Failed(error,) => return Parse::new(Failed(match error {
+      LengthError::Length(error,) => LengthError::Length(error,),
+      LengthError::Overflow => LengthError::Overflow,
+      LengthError::Incomplete(pending,) => LengthError::Incomplete(pending,),
+      LengthError::Value(never,) => never,
+      LengthError::Unused(never,) => never,
+    },), input,),
+  };
+
+  let Parse { value, unused: rest, } = parser(frame,);
+  match value {
+    Output(_,) if !rest.is_empty() => Parse::new(Failed(LengthError::Unused(rest,),), input,),
+    Output(value,) => Parse::new(Output(value,), unused,),
+    Pending(pending,) => Parse::new(Failed(LengthError::Incomplete(pending,),), input,),
+    Failed(error,) => Parse::new(Failed(LengthError::Value(error,),), input,),
+  }
+}
@@ -0,0 +1,305 @@
+//! The pieces of parsers built by the [`grammar!`](crate::grammar) macro.
+//! 
+//! The outputs of a sequence are collected into a flat tuple using [`single`](self::single)
+//! and [`append`](self::append) so actions receive one argument per element.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{*, parser::mapping::MapOk,};
+
+/// A tuple which can have a value appended to it.
+pub trait Append<T,> {
+  /// The tuple with the value appended.
+  type Output;
+
+  /// Appends `value` to this tuple.
+  fn append(self, value: T,) -> Self::Output;
+}
+
+/// Starts the tuple of outputs of a sequence.
+#[inline]
+pub fn single<A,>(value: A,) -> (A,) { (value,) }
+
+/// Appends the output of the next element of a sequence to the outputs before it.
+#[inline]
+pub fn append<A, T,>((tuple, value,): (A, T,),) -> A::Output
+  where A: Append<T,>, { tuple.append(value,) }
+
+/// Keeps the failure of the last alternative.
+#[inline]
+pub fn last<E, F,>((_, error,): (E, F,),) -> F { error }
+
+/// An action which is called with the outputs of a sequence as separate arguments.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Act<F,>(pub F,);
+
+/// Maps the successful output of `parser` using `action`.
+/// 
+/// Unlike [`Parser::map_ok`](crate::Parser::map_ok) `action` is required to be `Fn` so
+/// closures can be used within repetitions.
+#[inline]
+pub fn action<I, P, F, A, E, T,>(parser: Parser<P,>, action: F,) -> Parser<MapOk<Act<F,>, P,>,>
+  where P: ParserFnOnce<I, Value = PResult<A, E,>,>,
+    Act<F,>: Fn<(A,), Output = T>, { parser.map_ok(Act(action,),) }
+
+macro_rules! impl_tuples {
+  ($($name:ident,)*) => {
+    impl<$($name,)* T,> Append<T,> for ($($name,)*) {
+      type Output = ($($name,)* T,);
+
+      #[inline]
+      #[allow(non_snake_case,)]
+      fn append(self, value: T,) -> Self::Output {
+        let ($($name,)*) = self;
+        ($($name,)* value,)
+      }
+    }
+
+    impl<F, R, $($name,)*> FnOnce<(($($name,)*),)> for Act<F,>
+      where F: FnOnce($($name,)*) -> R, {
+      type Output = R;
+
+      #[inline]
+      #[allow(non_snake_case,)]
+      extern "rust-call" fn call_once(self, (($($name,)*),): (($($name,)*),),) -> R { (self.0)($($name,)*) }
+    }
+
+    impl<F, R, $($name,)*> FnMut<(($($name,)*),)> for Act<F,>
+      where F: FnMut($($name,)*) -> R, {
+      #[inline]
+      #[allow(non_snake_case,)]
+      extern "rust-call" fn call_mut(&mut self, (($($name,)*),): (($($name,)*),),) -> R { (self.0)($($name,)*) }
+    }
+
+    impl<F, R, $($name,)*> Fn<(($($name,)*),)> for Act<F,>
+      where F: Fn($($name,)*) -> R, {
+      #[inline]
+      #[allow(non_snake_case,)]
+      extern "rust-call" fn call(&self, (($($name,)*),): (($($name,)*),),) -> R { (self.0)($($name,)*) }
+    }
+  };
+}
+
+impl_tuples!(A,);
+impl_tuples!(A, B,);
+impl_tuples!(A, B, C,);
+impl_tuples!(A, B, C, D,);
+impl_tuples!(A, B, C, D, E,);
+impl_tuples!(A, B, C, D, E, G,);
+impl_tuples!(A, B, C, D, E, G, H,);
+impl_tuples!(A, B, C, D, E, G, H, J,);
+impl_tuples!(A, B, C, D, E, G, H, J, K,);
+impl_tuples!(A, B, C, D, E, G, H, J, K, L,);
+impl_tuples!(A, B, C, D, E, G, H, J, K, L, M,);
+impl_tuples!(A, B, C, D, E, G, H, J, K, L, M, N,);
+
+/// Declares functions parsing the rules of a grammar.
+/// 
+/// Each rule is written `fn name(Input) -> (Output, Error) = alternatives;` and expands to
+/// a function from the input to a `Parse<PResult<Output, Error>, Input>` built from the
+/// combinators of [`Parser`](crate::Parser). Rules may refer to each other recursively.
+/// 
+/// Alternatives are separated by `|` and are tried in order, if all fail the failure of
+/// the last is output. An alternative which fails or is pending returns its input. Each
+/// alternative is a sequence of elements optionally followed by `=> |args| action` where
+/// the action is a closure called with the output of each element. Without an action a
+/// sequence of a single element outputs that element and longer sequences output a tuple.
+/// If the body of an action contains `|` outside of brackets it must be wrapped in braces.
+/// 
+/// The elements are:
+/// 
+/// - a literal, which is a [`Tag`](crate::parser::sources::Tag) skipping the trivia of a
+///   [`Skipping`](crate::parser::lexeme::Skipping) input.
+/// - the name of a rule or a parser.
+/// - `{ parser }`, an expression producing a parser.
+/// - `( alternatives )`, a group.
+/// 
+/// Each followed by an optional `*`, `+` or `?` which applies [`many`](crate::Parser::many),
+/// [`many1`](crate::Parser::many1) or [`opt`](crate::Parser::opt), repetitions require the
+/// `alloc` feature. The failure of each element is converted into the rule's error type
+/// using `From`.
+/// 
+/// Large grammars may require the `recursion_limit` of the crate to be raised.
+/// 
+/// ```
+/// use ::parser::{*, parser::sources::{ByteClass, OneOf,},};
+/// 
+/// #[derive(PartialEq, Debug)]
+/// struct Expected;
+/// 
+/// impl From<&[u8]> for Expected {
+///   fn from(_: &[u8]) -> Self { Expected }
+/// }
+/// 
+/// impl From<&[u8; 1]> for Expected {
+///   fn from(_: &[u8; 1]) -> Self { Expected }
+/// }
+/// 
+/// grammar! {
+///   /// `expr := term "+" expr | term`
+///   fn expr(&[u8]) -> (u32, Expected) = term "+" expr => |lhs, _, rhs| lhs + rhs | term;
+///   fn term(&[u8]) -> (u32, Expected) = atom "*" term => |lhs, _, rhs| lhs * rhs | atom;
+///   fn atom(&[u8]) -> (u32, Expected) = "(" expr ")" => |_, expr, _| expr
+///     | { OneOf::new(ByteClass::DIGIT) } => |&[digit]| (digit - b'0') as u32;
+/// }
+/// 
+/// assert_eq!(expr(b"2*(3+4);"), (Output(14), &b";"[..]));
+/// assert_eq!(expr(b"1+3*2;"), (Output(7), &b";"[..]));
+/// assert_eq!(expr(b"2*(3+4"), (Pending(1), &b"2*(3+4"[..]));
+/// assert_eq!(expr(b"+1"), (Failed(Expected), &b"+1"[..]));
+/// ```
+/// 
+/// Rules over a [`Skipping`](crate::parser::lexeme::Skipping) input skip its trivia after
+/// each literal, other tokens skip it when wrapped in
+/// [`skip_trivia`](crate::Parser::skip_trivia).
+/// 
+/// ```
+/// use ::parser::{*, parser::{sources::{ByteClass, OneOf, Trivia,}, lexeme::Skipping,},};
+/// 
+/// #[derive(PartialEq, Debug)]
+/// struct Expected;
+/// 
+/// impl From<&[u8]> for Expected {
+///   fn from(_: &[u8]) -> Self { Expected }
+/// }
+/// 
+/// impl From<&[u8; 1]> for Expected {
+///   fn from(_: &[u8; 1]) -> Self { Expected }
+/// }
+/// 
+/// type Input<'a> = Skipping<&'a [u8], Trivia>;
+/// 
+/// grammar! {
+///   fn sum(Input) -> (u32, Expected) = digit "+" sum => |lhs, _, rhs| lhs + rhs | digit;
+///   fn digit(Input) -> (u32, Expected) = { Parser::new(OneOf::new(ByteClass::DIGIT)).skip_trivia::<Input>() }
+///     => |&[digit]| (digit - b'0') as u32;
+/// }
+/// 
+/// let input = Skipping::new(&b"1 + 2\n+3;"[..], Trivia::WHITESPACE);
+/// assert_eq!(sum(input), (Output(6), Skipping::new(&b";"[..], Trivia::WHITESPACE)));
+/// ```
+#[macro_export]
+macro_rules! grammar {
+  (@rules) => {};
+  (@rules $(#[$meta:meta])* $vis:vis fn $name:ident($input:ty) -> ($out:ty, $err:ty) = $($rest:tt)*) => {
+    $crate::grammar!(@rule [$(#[$meta])*] [$vis] $name [$input] [$out] [$err] [] $($rest)*);
+  };
+  (@rule $meta:tt $vis:tt $name:ident $input:tt $out:tt $err:tt [$($body:tt)*] ; $($rest:tt)*) => {
+    $crate::grammar!(@fn $meta $vis $name $input $out $err $($body)*);
+    $crate::grammar!(@rules $($rest)*);
+  };
+  (@rule $meta:tt $vis:tt $name:ident $input:tt $out:tt $err:tt [$($body:tt)*] $token:tt $($rest:tt)*) => {
+    $crate::grammar!(@rule $meta $vis $name $input $out $err [$($body)* $token] $($rest)*);
+  };
+  (@fn [$($meta:tt)*] [$vis:vis] $name:ident [$input:ty] [$out:ty] [$err:ty] $($body:tt)+) => {
+    $($meta)*
+    $vis fn $name(input: $input,) -> $crate::Parse<$crate::PResult<$out, $err,>, $input,> {
+      $crate::parser::ParserFnOnce::parse_once(
+        $crate::grammar!(@alts [$input] [$err] [] [] $($body)+),
+        input,
+      )
+    }
+  };
+  (@alts $input:tt $err:tt $done:tt $seq:tt => | $($rest:tt)*) => {
+    $crate::grammar!(@params $input $err $done $seq [] $($rest)*)
+  };
+  (@alts $input:tt $err:tt [$($done:tt)*] [$($seq:tt)+] | $($rest:tt)*) => {
+    $crate::grammar!(@alts $input $err [$($done)* {[$($seq)+] []}] [] $($rest)*)
+  };
+  (@alts $input:tt $err:tt [$($done:tt)*] [$($seq:tt)+]) => {
+    $crate::grammar!(@or $input $err $($done)* {[$($seq)+] []})
+  };
+  (@alts $input:tt $err:tt $done:tt [$($seq:tt)*] $token:tt $($rest:tt)*) => {
+    $crate::grammar!(@alts $input $err $done [$($seq)* $token] $($rest)*)
+  };
+  (@params $input:tt $err:tt $done:tt $seq:tt [$($params:tt)*] | $($rest:tt)*) => {
+    $crate::grammar!(@action $input $err $done $seq [$($params)*] [] $($rest)*)
+  };
+  (@params $input:tt $err:tt $done:tt $seq:tt [$($params:tt)*] $token:tt $($rest:tt)*) => {
+    $crate::grammar!(@params $input $err $done $seq [$($params)* $token] $($rest)*)
+  };
+  (@action $input:tt $err:tt [$($done:tt)*] $seq:tt [$($params:tt)*] [$($body:tt)+] | $($rest:tt)*) => {
+    $crate::grammar!(@alts $input $err [$($done)* {$seq [|$($params)*| $($body)+]}] [] $($rest)*)
+  };
+  (@action $input:tt $err:tt [$($done:tt)*] $seq:tt [$($params:tt)*] [$($body:tt)+]) => {
+    $crate::grammar!(@or $input $err $($done)* {$seq [|$($params)*| $($body)+]})
+  };
+  (@action $input:tt $err:tt $done:tt $seq:tt $params:tt [$($body:tt)*] $token:tt $($rest:tt)*) => {
+    $crate::grammar!(@action $input $err $done $seq $params [$($body)* $token] $($rest)*)
+  };
+  (@or $input:tt $err:tt $first:tt $($rest:tt)*) => {
+    $crate::grammar!(@or_fold $input $err ($crate::grammar!(@alt $input $err $first)) $($rest)*)
+  };
+  (@or_fold $input:tt $err:tt $acc:tt) => { $acc };
+  (@or_fold [$input:ty] $err:tt $acc:tt $next:tt $($rest:tt)*) => {
+    $crate::grammar!(@or_fold [$input] $err
+      ($acc.or::<$input, _,>($crate::grammar!(@alt [$input] $err $next))
+        .map_err::<$input, _,>($crate::parser::grammar::last))
+      $($rest)*
+    )
+  };
+  (@alt $input:tt $err:tt {[$($seq:tt)+] []}) => {
+    $crate::Parser::new($crate::parser::sequence::Backtrack(
+      $crate::grammar!(@elems value $input $err [] $($seq)+),
+    ),)
+  };
+  (@alt [$input:ty] $err:tt {[$($seq:tt)+] [$($action:tt)+]}) => {
+    $crate::Parser::new($crate::parser::sequence::Backtrack(
+      $crate::parser::grammar::action::<$input, _, _, _, _, _,>(
+        $crate::grammar!(@elems tuple [$input] $err [] $($seq)+),
+        $($action)+
+      ),
+    ),)
+  };
+  (@elems $mode:ident [$input:ty] [$err:ty] $elems:tt $tag:literal $($rest:tt)*) => {
+    $crate::grammar!(@postfix $mode [$input] [$err] $elems
+      ($crate::Parser::new($crate::parser::lexeme::SkipTrivia($crate::parser::sources::Tag::new($tag,),),)
+        .map_err::<$input, _,>(<$err as ::core::convert::From<_>>::from))
+      $($rest)*
+    )
+  };
+  (@elems $mode:ident [$input:ty] [$err:ty] $elems:tt $rule:ident $($rest:tt)*) => {
+    $crate::grammar!(@postfix $mode [$input] [$err] $elems
+      ($crate::Parser::new($rule,).map_err::<$input, _,>(<$err as ::core::convert::From<_>>::from))
+      $($rest)*
+    )
+  };
+  (@elems $mode:ident [$input:ty] [$err:ty] $elems:tt { $($parser:tt)+ } $($rest:tt)*) => {
+    $crate::grammar!(@postfix $mode [$input] [$err] $elems
+      ($crate::Parser::new({ $($parser)+ },).map_err::<$input, _,>(<$err as ::core::convert::From<_>>::from))
+      $($rest)*
+    )
+  };
+  (@elems $mode:ident $input:tt $err:tt $elems:tt ( $($group:tt)+ ) $($rest:tt)*) => {
+    $crate::grammar!(@postfix $mode $input $err $elems
+      ($crate::Parser::new($crate::grammar!(@alts $input $err [] [] $($group)+),))
+      $($rest)*
+    )
+  };
+  (@elems value $input:tt $err:tt [$elem:tt]) => { $elem };
+  (@elems $mode:ident [$input:ty] $err:tt [$first:tt $($elems:tt)*]) => {
+    $crate::grammar!(@fold [$input] ($first.map_ok::<$input, _,>($crate::parser::grammar::single)) $($elems)*)
+  };
+  (@fold $input:tt $acc:tt) => { $acc };
+  (@fold [$input:ty] $acc:tt $next:tt $($rest:tt)*) => {
+    $crate::grammar!(@fold [$input]
+      ($acc.and_ok::<$input, _,>($next).map_ok::<$input, _,>($crate::parser::grammar::append))
+      $($rest)*
+    )
+  };
+  (@postfix $mode:ident [$input:ty] $err:tt [$($elems:tt)*] $elem:tt * $($rest:tt)*) => {
+    $crate::grammar!(@elems $mode [$input] $err [$($elems)* ($elem.many::<$input>())] $($rest)*)
+  };
+  (@postfix $mode:ident [$input:ty] $err:tt [$($elems:tt)*] $elem:tt + $($rest:tt)*) => {
+    $crate::grammar!(@elems $mode [$input] $err [$($elems)* ($elem.many1::<$input>())] $($rest)*)
+  };
+  (@postfix $mode:ident [$input:ty] $err:tt [$($elems:tt)*] $elem:tt ? $($rest:tt)*) => {
+    $crate::grammar!(@elems $mode [$input] $err [$($elems)* ($elem.opt::<$input>())] $($rest)*)
+  };
+  (@postfix $mode:ident $input:tt $err:tt [$($elems:tt)*] $elem:tt $($rest:tt)*) => {
+    $crate::grammar!(@elems $mode $input $err [$($elems)* $elem] $($rest)*)
+  };
+  ($($rules:tt)*) => { $crate::grammar!(@rules $($rules)*); };
+}
@@ -0,0 +1,352 @@
+//! Parsers for indentation sensitive grammars.
+//! 
+//! The indentation of the enclosing block is carried in the [`Indent`](self::Indent) state
+//! of a [`Stateful`](crate::parser::state::Stateful) input. Lines containing only
+//! whitespace are skipped when measuring indentation.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+use core::num::NonZeroUsize;
+#[cfg(feature = "alloc",)]
+use {super::sources::TerminatedLine, alloc::vec::Vec,};
+
+/// The characters which may be used to indent a line.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum IndentPolicy {
+  /// Only spaces may indent a line.
+  Spaces,
+  /// Only tabs may indent a line, each tab is one column.
+  Tabs,
+  /// Spaces and tabs may indent a line, a tab advances to the next multiple of the width.
+  TabWidth(NonZeroUsize,),
+}
+
+impl IndentPolicy {
+  /// Constructs a new `TabWidth` policy from `width`.
+  /// 
+  /// # Panics
+  /// 
+  /// If `width` is zero.
+  #[inline]
+  pub const fn tab_width(width: usize,) -> Self {
+    match NonZeroUsize::new(width,) {
+      Some(width,) => IndentPolicy::TabWidth(width,),
+      None => panic!("`IndentPolicy::TabWidth` must not be zero"),
+    }
+  }
+}
+
+/// The error produced by inconsistent indentation.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum IndentError {
+  /// The line was indented with a character forbidden by the policy.
+  Forbidden(char,),
+  /// The line was not indented deeper than its enclosing block, it was at the given column.
+  NotDeeper(usize,),
+  /// The line was not indented to the column of its block, it was at the given column.
+  NotSame(usize,),
+  /// The line was dedented to the given column which matches no enclosing block.
+  Unaligned(usize,),
+}
+
+/// The indentation state of a `Stateful` input.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub struct Indent {
+  /// The characters which may be used to indent a line.
+  pub policy: IndentPolicy,
+  /// The indentation column of the enclosing block.
+  pub level: usize,
+}
+
+impl Indent {
+  /// Constructs a new `Indent` at the first column.
+  #[inline]
+  pub const fn new(policy: IndentPolicy,) -> Self { Indent { policy, level: 0, } }
+  /// Measures the indentation of the next line which is not blank, returning its column
+  /// and the length of the blank lines and indentation.
+  /// 
+  /// The policy is only checked against the indentation of the line which is not blank.
+  pub fn measure(&self, input: &str,) -> PResult<(usize, usize,), IndentError,> {
+    let (mut index, mut column, mut forbidden,) = (0, 0, None,);
+    loop {
+      match (input.as_bytes().get(index,), self.policy,) {
+        (Some(b' ',), IndentPolicy::Tabs,) => { forbidden = forbidden.or(Some(' ',),); column += 1 },
+        (Some(b'\t',), IndentPolicy::Spaces,) => { forbidden = forbidden.or(Some('\t',),); column += 1 },
+        (Some(b' ',), _,) | (Some(b'\t',), IndentPolicy::Tabs,) => column += 1,
+        (Some(b'\t',), IndentPolicy::TabWidth(width,),) => column = (column / width.get() + 1) * width.get(),
+        //A blank line does not change the indentation.
+        (Some(b'\n',), _,) => { column = 0; forbidden = None },
+        (Some(b'\r',), _,) if input.as_bytes().get(index + 1,) == Some(&b'\n',) => {
+          index += 1;
+          column = 0;
+          forbidden = None;
+        },
+        (Some(b'\r',), _,) if index + 1 == input.len() => return Pending(1,),
+        (Some(_,), _,) => return match forbidden {
+          Some(c,) => Failed(IndentError::Forbidden(c,),),
+          None => Output((column, index,),),
+        },
+        (None, _,) => return Pending(1,),
+      }
+
+      index += 1;
+    }
+  }
+}
+
+/// A parser which accepts the indentation of a line at the same column as its block.
+/// 
+/// The column is output.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct SameIndent;
+
+impl<'a,> FnOnce<(Stateful<&'a str, Indent,>,),> for SameIndent {
+  type Output = Parse<PResult<usize, IndentError,>, Stateful<&'a str, Indent,>,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (Stateful<&'a str, Indent,>,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(Stateful<&'a str, Indent,>,),> for SameIndent {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (Stateful<&'a str, Indent,>,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(Stateful<&'a str, Indent,>,),> for SameIndent {
+  extern "rust-call" fn call(&self, (input,): (Stateful<&'a str, Indent,>,),) -> Self::Output {
+    match input.state.measure(input.input,) {
+      Output((column, len,),) if column == input.state.level => {
+        Parse::new(Output(column,), Stateful::new(&input.input[len..], input.state,),)
+      },
+      Output((column, _,),) => Parse::new(Failed(IndentError::NotSame(column,),), input,),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(error,) => Parse::new(Failed(error,), input,),
+    }
+  }
+}
+
+/// A parser which accepts the indentation of a line deeper than its block.
+/// 
+/// The column is output, the indentation of the block is left unchanged.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct DeeperIndent;
+
+impl<'a,> FnOnce<(Stateful<&'a str, Indent,>,),> for DeeperIndent {
+  type Output = Parse<PResult<usize, IndentError,>, Stateful<&'a str, Indent,>,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (Stateful<&'a str, Indent,>,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(Stateful<&'a str, Indent,>,),> for DeeperIndent {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (Stateful<&'a str, Indent,>,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(Stateful<&'a str, Indent,>,),> for DeeperIndent {
+  extern "rust-call" fn call(&self, (input,): (Stateful<&'a str, Indent,>,),) -> Self::Output {
+    match input.state.measure(input.input,) {
+      Output((column, len,),) if column > input.state.level => {
+        Parse::new(Output(column,), Stateful::new(&input.input[len..], input.state,),)
+      },
+      Output((column, _,),) => Parse::new(Failed(IndentError::NotDeeper(column,),), input,),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(error,) => Parse::new(Failed(error,), input,),
+    }
+  }
+}
+
+/// The error produced by an `IndentedBlock`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum BlockError<E,> {
+  /// The indentation of the block was inconsistent.
+  Indent(IndentError,),
+  /// An item of the block failed.
+  Item(E,),
+}
+
+/// A parser which accepts a block of items indented deeper than the enclosing block.
+/// 
+/// The first line of the block sets its indentation, every item must begin on a line at
+/// that indentation and must consume its line ending. The block ends at the first line
+/// indented to the column of an enclosing block, that line is not consumed. If the input
+/// ends the block is pending as more items could follow.
+#[cfg(feature = "alloc",)]
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct IndentedBlock<P,>(pub P,);
+
+#[cfg(feature = "alloc",)]
+impl<P,> IndentedBlock<P,> {
+  /// Constructs a new `IndentedBlock` of `item`s.
+  #[inline]
+  pub const fn new(item: P,) -> Self { IndentedBlock(item,) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<'a, P, T, E,> FnOnce<(Stateful<&'a str, Indent,>,),> for IndentedBlock<P,>
+  where P: ParserFn<Stateful<&'a str, Indent,>, Value = PResult<T, E,>,>, {
+  type Output = Parse<PResult<Vec<T>, BlockError<E,>,>, Stateful<&'a str, Indent,>,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (Stateful<&'a str, Indent,>,),) -> Self::Output { (&self)(input,) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<'a, P, T, E,> FnMut<(Stateful<&'a str, Indent,>,),> for IndentedBlock<P,>
+  where P: ParserFn<Stateful<&'a str, Indent,>, Value = PResult<T, E,>,>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (Stateful<&'a str, Indent,>,),) -> Self::Output { (&*self)(input,) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<'a, P, T, E,> Fn<(Stateful<&'a str, Indent,>,),> for IndentedBlock<P,>
+  where P: ParserFn<Stateful<&'a str, Indent,>, Value = PResult<T, E,>,>, {
+  extern "rust-call" fn call(&self, (input,): (Stateful<&'a str, Indent,>,),) -> Self::Output {
+    let Stateful { input: mut rest, state, } = input;
+    let (column, len,) = match state.measure(rest,) {
+      Output((column, len,),) if column > state.level => (column, len,),
+      Output((column, _,),) => return Parse::new(Failed(BlockError::Indent(IndentError::NotDeeper(column,),),), input,),
+      Pending(pending,) => return Parse::new(Pending(pending,), input,),
+      Failed(error,) => return Parse::new(Failed(BlockError::Indent(error,),), input,),
+    };
+    let block = Indent { level: column, ..state };
+
+    let mut items = Vec::new();
+    rest = &rest[len..];
+    loop {
+      match self.0.parse(Stateful::new(rest, block,),) {
+        Parse { value: Output(item,), unused, } => { items.push(item,); rest = unused.input },
+        Parse { value: Pending(pending,), .. } => return Parse::new(Pending(pending,), input,),
+        Parse { value: Failed(error,), .. } => return Parse::new(Failed(BlockError::Item(error,),), input,),
+      }
+
+      let error = match block.measure(rest,) {
+        Output((next, len,),) if next == column => { rest = &rest[len..]; continue },
+        Output((next, _,),) if next > column => IndentError::NotSame(next,),
+        //The block ends at a dedent, the enclosing block continues or ends in turn.
+        Output((next, _,),) if next <= state.level => return Parse::new(Output(items,), Stateful::new(rest, state,),),
+        Output((next, _,),) => IndentError::Unaligned(next,),
+        Pending(pending,) => return Parse::new(Pending(pending,), input,),
+        Failed(error,) => error,
+      };
+
+      return Parse::new(Failed(BlockError::Indent(error,),), input,)
+    }
+  }
+}
+
+/// A token of the layout of an indented input.
+#[cfg(feature = "alloc",)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum LayoutToken<'a,> {
+  /// The following lines are indented deeper.
+  Indent,
+  /// The block of the preceding lines has ended.
+  Dedent,
+  /// A line without its indentation or line ending.
+  Line(&'a str,),
+}
+
+/// An iterator which splits an indented input into lines and `Indent` and `Dedent`
+/// markers.
+/// 
+/// The indentation of each open block is kept on a stack. The input is treated as complete
+/// so a final line without a line ending is produced as a line, and when only blank lines
+/// remain a `Dedent` is produced for each open block before iteration stops. Iteration
+/// also stops after an error, the rest of the input can then be taken with `into_unused`
+/// and the number of blocks still open with `depth`.
+/// 
+/// ```
+/// use ::parser::{*, parser::indent::*,};
+/// 
+/// let layout = Layout::new("a\n  b\n    c\nd\n  e\n\n", IndentPolicy::Spaces);
+/// let tokens = layout.map(PResult::unwrap).collect::<Vec<_>>();
+/// assert_eq!(tokens, [
+///   LayoutToken::Line("a"), LayoutToken::Indent, LayoutToken::Line("b"), LayoutToken::Indent,
+///   LayoutToken::Line("c"), LayoutToken::Dedent, LayoutToken::Dedent, LayoutToken::Line("d"),
+///   LayoutToken::Indent, LayoutToken::Line("e"), LayoutToken::Dedent,
+/// ]);
+/// let layout = Layout::new("a\n  b", IndentPolicy::Spaces);
+/// let tokens = layout.map(PResult::unwrap).collect::<Vec<_>>();
+/// assert_eq!(tokens, [
+///   LayoutToken::Line("a"), LayoutToken::Indent, LayoutToken::Line("b"), LayoutToken::Dedent,
+/// ]);
+/// let layout = Layout::new("a\n  \n\tb\n", IndentPolicy::Tabs);
+/// let tokens = layout.map(PResult::unwrap).collect::<Vec<_>>();
+/// assert_eq!(tokens, [
+///   LayoutToken::Line("a"), LayoutToken::Indent, LayoutToken::Line("b"), LayoutToken::Dedent,
+/// ]);
+/// ```
+#[cfg(feature = "alloc",)]
+#[derive(Clone, Debug,)]
+pub struct Layout<'a,> {
+  /// The remaining input.
+  input: &'a str,
+  /// The characters which may be used to indent a line.
+  policy: IndentPolicy,
+  /// The columns of the open blocks.
+  stack: Vec<usize>,
+  /// Whether an error has been produced.
+  failed: bool,
+}
+
+#[cfg(feature = "alloc",)]
+impl<'a,> Layout<'a,> {
+  /// Constructs a new `Layout` of `input` using `policy`.
+  #[inline]
+  pub fn new(input: &'a str, policy: IndentPolicy,) -> Self {
+    Layout { input, policy, stack: Vec::new(), failed: false, }
+  }
+  /// The number of blocks still open.
+  #[inline]
+  pub fn depth(&self,) -> usize { self.stack.len() }
+  /// The input which has not been split into lines.
+  #[inline]
+  pub const fn unused(&self,) -> &'a str { self.input }
+  /// Returns the input which has not been split into lines.
+  #[inline]
+  pub fn into_unused(self,) -> &'a str { self.input }
+}
+
+#[cfg(feature = "alloc",)]
+impl<'a,> Iterator for Layout<'a,> {
+  type Item = PResult<LayoutToken<'a,>, IndentError,>;
+
+  fn next(&mut self,) -> Option<Self::Item> {
+    if self.failed { return None }
+
+    let level = self.stack.last().copied().unwrap_or(0,);
+    let (column, len,) = match (Indent { policy: self.policy, level, }).measure(self.input,) {
+      Output(measure,) => measure,
+      //Only blank lines remain so the open blocks are closed.
+      Pending(_,) => return self.stack.pop().map(|_,| Output(LayoutToken::Dedent,),),
+      Failed(error,) => { self.failed = true; return Some(Failed(error,),) },
+    };
+
+    if column > level {
+      self.stack.push(column,);
+      return Some(Output(LayoutToken::Indent,),)
+    }
+    if column < level {
+      self.stack.pop();
+      let outer = self.stack.last().copied().unwrap_or(0,);
+      if column > outer {
+        self.failed = true;
+        return Some(Failed(IndentError::Unaligned(column,),),)
+      }
+
+      return Some(Output(LayoutToken::Dedent,),)
+    }
+
+    let line = match TerminatedLine::new().parse(&self.input[len..],) {
+      Parse { value: Output(line,), unused, } => { self.input = unused; line },
+      //The final line has no line ending.
+      Parse { unused, .. } => { self.input = ""; unused.strip_suffix('\r',).unwrap_or(unused,) },
+    };
+
+    Some(Output(LayoutToken::Line(line,),),)
+  }
+}
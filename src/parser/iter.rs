@@ -0,0 +1,87 @@
+//! Repeated application of a parser to its own unused input.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{*, parser::Remaining,};
+
+/// The reason a `ParseIter` stopped.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum Stop<E,> {
+  /// The input was empty.
+  Eof,
+  /// The parser failed with the error.
+  Failed(E,),
+  /// The parser needed more tokens than the input held.
+  Pending(usize,),
+  /// The parser produced an output without consuming any input.
+  Stalled,
+}
+
+/// An iterator which applies a parser to the input unused by its previous output.
+/// 
+/// Each output of the parser is yielded. Iteration stops when the input is empty or the
+/// parser fails or is pending, the rest of the input and the reason iteration stopped can
+/// then be inspected. If the parser outputs without consuming any input that output is
+/// yielded and iteration stops, as it would otherwise repeat forever.
+#[derive(Clone, Copy, Debug,)]
+pub struct ParseIter<P, I, E,> {
+  /// The parser to apply.
+  parser: P,
+  /// The remaining input.
+  input: I,
+  /// The reason iteration stopped.
+  stop: Option<Stop<E,>>,
+}
+
+impl<P, I, E,> ParseIter<P, I, E,> {
+  /// Constructs a new `ParseIter` applying `parser` to `input`.
+  #[inline]
+  pub const fn new(parser: P, input: I,) -> Self { ParseIter { parser, input, stop: None, } }
+  /// The input which has not been parsed.
+  #[inline]
+  pub const fn unused(&self,) -> &I { &self.input }
+  /// Returns the input which has not been parsed.
+  #[inline]
+  pub fn into_unused(self,) -> I { self.input }
+  /// The reason iteration stopped, if it has.
+  #[inline]
+  pub const fn stop(&self,) -> Option<&Stop<E,>> { self.stop.as_ref() }
+  /// Returns the input which has not been parsed and the reason iteration stopped, if it
+  /// has.
+  #[inline]
+  pub fn into_parts(self,) -> (I, Option<Stop<E,>>,) { (self.input, self.stop,) }
+}
+
+impl<P, I, T, E,> Iterator for ParseIter<P, I, E,>
+  where P: ParserFnMut<I, Value = PResult<T, E,>,>,
+    I: Remaining + Clone, {
+  type Item = T;
+
+  fn next(&mut self,) -> Option<Self::Item> {
+    if self.stop.is_some() { return None }
+
+    let remaining = self.input.remaining();
+    if remaining == 0 {
+      self.stop = Some(Stop::Eof,);
+      return None
+    }
+
+    let Parse { value, unused, } = self.parser.parse_mut(self.input.clone(),);
+    match value {
+      Output(output,) => {
+        if unused.remaining() == remaining { self.stop = Some(Stop::Stalled,) }
+        self.input = unused;
+        Some(output,)
+      },
+      Failed(error,) => {
+        self.stop = Some(Stop::Failed(error,),);
+        None
+      },
+      Pending(pending,) => {
+        self.stop = Some(Stop::Pending(pending,),);
+        None
+      },
+    }
+  }
+}
@@ -0,0 +1,120 @@
+//! Left recursive rules using seed growing.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+use super::Remaining;
+use core::cell::RefCell;
+use alloc::collections::BTreeMap;
+
+/// The parser a `LeftRec` rule body uses to apply the rule recursively.
+/// 
+/// If the rule is re-entered at the offset it is already being applied at the seed
+/// grown so far is returned, or `Failed(None)` if there is no seed yet.
+pub type Recur<'r, T, E, I,> = &'r dyn Fn(I,) -> Parse<PResult<T, Option<E>,>, I,>;
+
+/// The state of a rule being applied at an offset.
+#[derive(Debug,)]
+struct Seed<V, I,> {
+  /// The longest parse of the rule so far.
+  parse: Option<Parse<V, I,>>,
+  /// Whether the rule was re-entered at the offset.
+  recursed: bool,
+}
+
+/// A parser for a rule which may be directly left recursive.
+/// 
+/// The body of the rule is passed a `Recur` parser to apply the rule recursively. When
+/// the rule re-enters itself at the same offset the recursion fails, leaving the other
+/// alternatives of the body to parse a seed. The body is then applied again with the seed
+/// as the output of the recursion, growing the seed until the parse no longer gets
+/// longer.
+/// 
+/// If growing the seed is pending the rule is pending.
+#[derive(Debug,)]
+pub struct LeftRec<F, V, I,> {
+  /// The body of the rule.
+  pub body: F,
+  /// The seeds of the offsets the rule is being applied at.
+  seeds: RefCell<BTreeMap<usize, Seed<V, I,>,>,>,
+}
+
+impl<F, T, E, I,> LeftRec<F, PResult<T, E,>, I,>
+  where F: Fn(Recur<'_, T, E, I,>, I,) -> Parse<PResult<T, E,>, I,>,
+    T: Clone,
+    I: Remaining + Clone, {
+  /// Constructs a new `LeftRec` rule from its `body`.
+  #[inline]
+  pub fn new(body: F,) -> Self { LeftRec { body, seeds: RefCell::new(BTreeMap::new(),), } }
+  /// Applies the rule to `input`, growing a seed if it recurses.
+  fn rule(&self, input: I,) -> Parse<PResult<T, E,>, I,> {
+    let key = input.remaining();
+    self.seeds.borrow_mut().insert(key, Seed { parse: None, recursed: false, },);
+
+    let recur = |input: I,| self.recur(input,);
+    let mut parse = (self.body)(&recur, input.clone(),);
+    let parse = loop {
+      let mut seeds = self.seeds.borrow_mut();
+      let seed = seeds.get_mut(&key,).expect("the seed is removed before the rule returns",);
+      match (parse, seed.parse.take(),) {
+        (Parse { value: Output(value,), unused, }, Some(longest,),) => {
+          //The seed stops growing once the parse is no longer longer.
+          if unused.remaining() >= longest.unused.remaining() { break longest }
+
+          seed.parse = Some(Parse::new(Output(value,), unused,),);
+        },
+        (Parse { value: Output(value,), unused, }, None,) if seed.recursed => {
+          seed.parse = Some(Parse::new(Output(value,), unused,),);
+        },
+        (Parse { value: Failed(_,), .. }, Some(longest,),) => break longest,
+        (Parse { value: Pending(pending,), .. }, _,) => break Parse::new(Pending(pending,), input,),
+        (parse, _,) => break parse,
+      }
+      drop(seeds,);
+
+      parse = (self.body)(&recur, input.clone(),);
+    };
+    self.seeds.borrow_mut().remove(&key,);
+
+    parse
+  }
+  /// Applies the rule recursively to `input`.
+  fn recur(&self, input: I,) -> Parse<PResult<T, Option<E>,>, I,> {
+    if let Some(seed,) = self.seeds.borrow_mut().get_mut(&input.remaining(),) {
+      seed.recursed = true;
+      return match &seed.parse {
+        Some(Parse { value: Output(value,), unused, },) => Parse::new(Output(value.clone(),), unused.clone(),),
+        _ => Parse::new(Failed(None,), input,),
+      }
+    }
+
+    self.rule(input,).map(|value,| value.map_fail(Some,),)
+  }
+}
+
+impl<F, T, E, I,> FnOnce<(I,),> for LeftRec<F, PResult<T, E,>, I,>
+  where F: Fn(Recur<'_, T, E, I,>, I,) -> Parse<PResult<T, E,>, I,>,
+    T: Clone,
+    I: Remaining + Clone, {
+  type Output = Parse<PResult<T, E,>, I,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output { self.rule(input,) }
+}
+
+impl<F, T, E, I,> FnMut<(I,),> for LeftRec<F, PResult<T, E,>, I,>
+  where F: Fn(Recur<'_, T, E, I,>, I,) -> Parse<PResult<T, E,>, I,>,
+    T: Clone,
+    I: Remaining + Clone, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output { self.rule(input,) }
+}
+
+impl<F, T, E, I,> Fn<(I,),> for LeftRec<F, PResult<T, E,>, I,>
+  where F: Fn(Recur<'_, T, E, I,>, I,) -> Parse<PResult<T, E,>, I,>,
+    T: Clone,
+    I: Remaining + Clone, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output { self.rule(input,) }
+}
@@ -0,0 +1,307 @@
+//! Adaptors which skip trivia around the tokens of a grammar.
+//! 
+//! Trivia can be skipped around individual tokens with [`Lexeme`](self::Lexeme) and
+//! [`Token`](self::Token), or for a whole grammar by parsing a
+//! [`Skipping`](self::Skipping) input which carries the trivia parser of the grammar.
+//! Tokens wrapped in [`SkipTrivia`](self::SkipTrivia) skip the trivia carried by a
+//! `Skipping` input after they are accepted.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+
+/// A parser which skips the trivia following the output of the inner parser.
+/// 
+/// The trivia parser must not fail. If the input ends within the trailing trivia the
+/// parse is pending.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Lexeme<P, T,> {
+  /// The parser for the token.
+  pub parser: P,
+  /// The parser for the trivia.
+  pub trivia: T,
+}
+
+impl<P, T,> Lexeme<P, T,> {
+  /// Constructs a new `Lexeme` from the `parser` and `trivia` parsers.
+  #[inline]
+  pub const fn new(parser: P, trivia: T,) -> Self { Lexeme { parser, trivia, } }
+}
+
+impl<P, T, V, E, S, I,> FnOnce<(I,),> for Lexeme<P, T,>
+  where P: ParserFnOnce<I, Value = PResult<V, E,>,>,
+    T: ParserFnOnce<I, Value = PResult<S, !,>,>,
+    I: Clone, {
+  type Output = Parse<PResult<V, E,>, I,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output {
+    let trivia = self.trivia;
+    lexeme(self.parser.parse_once(input.clone(),), input, |unused,| trivia.parse_once(unused,),)
+  }
+}
+
+impl<P, T, V, E, S, I,> FnMut<(I,),> for Lexeme<P, T,>
+  where P: ParserFnMut<I, Value = PResult<V, E,>,>,
+    T: ParserFnMut<I, Value = PResult<S, !,>,>,
+    I: Clone, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output {
+    let trivia = &mut self.trivia;
+    lexeme(self.parser.parse_mut(input.clone(),), input, |unused,| trivia.parse_mut(unused,),)
+  }
+}
+
+impl<P, T, V, E, S, I,> Fn<(I,),> for Lexeme<P, T,>
+  where P: ParserFn<I, Value = PResult<V, E,>,>,
+    T: ParserFn<I, Value = PResult<S, !,>,>,
+    I: Clone, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output {
+    lexeme(self.parser.parse(input.clone(),), input, |unused,| self.trivia.parse(unused,),)
+  }
+}
+
+/// A parser which skips the trivia preceding the inner parser.
+/// 
+/// The trivia parser must not fail. If the input ends within the leading trivia the parse
+/// is pending.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Token<P, T,> {
+  /// The parser for the token.
+  pub parser: P,
+  /// The parser for the trivia.
+  pub trivia: T,
+}
+
+impl<P, T,> Token<P, T,> {
+  /// Constructs a new `Token` from the `parser` and `trivia` parsers.
+  #[inline]
+  pub const fn new(parser: P, trivia: T,) -> Self { Token { parser, trivia, } }
+}
+
+impl<P, T, V, E, S, I,> FnOnce<(I,),> for Token<P, T,>
+  where P: ParserFnOnce<I, Value = PResult<V, E,>,>,
+    T: ParserFnOnce<I, Value = PResult<S, !,>,>,
+    I: Clone, {
+  type Output = Parse<PResult<V, E,>, I,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output {
+    let parser = self.parser;
+    token(self.trivia.parse_once(input.clone(),), input, |unused,| parser.parse_once(unused,),)
+  }
+}
+
+impl<P, T, V, E, S, I,> FnMut<(I,),> for Token<P, T,>
+  where P: ParserFnMut<I, Value = PResult<V, E,>,>,
+    T: ParserFnMut<I, Value = PResult<S, !,>,>,
+    I: Clone, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output {
+    let parser = &mut self.parser;
+    token(self.trivia.parse_mut(input.clone(),), input, |unused,| parser.parse_mut(unused,),)
+  }
+}
+
+impl<P, T, V, E, S, I,> Fn<(I,),> for Token<P, T,>
+  where P: ParserFn<I, Value = PResult<V, E,>,>,
+    T: ParserFn<I, Value = PResult<S, !,>,>,
+    I: Clone, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output {
+    token(self.trivia.parse(input.clone(),), input, |unused,| self.parser.parse(unused,),)
+  }
+}
+
+/// An input adaptor which carries the trivia parser of a grammar alongside an input.
+/// 
+/// The trivia parser must not fail. Parsers wrapped in `SkipTrivia` skip the trivia
+/// following their output, other parsers see the trivia as part of the input.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Skipping<I, T,> {
+  /// The remaining input.
+  pub input: I,
+  /// The parser for the trivia.
+  pub trivia: T,
+}
+
+impl<I, T,> Skipping<I, T,> {
+  /// Constructs a new `Skipping` carrying `trivia` alongside `input`.
+  #[inline]
+  pub const fn new(input: I, trivia: T,) -> Self { Skipping { input, trivia, } }
+}
+
+/// A parser which skips the trivia of a `Skipping` input following the output of the
+/// inner parser.
+/// 
+/// On slice and `&str` inputs the inner parser is applied unchanged, so the literals of a
+/// [`grammar!`](crate::grammar) are wrapped in `SkipTrivia` whatever the input of the
+/// grammar is.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct SkipTrivia<P,>(pub P,);
+
+impl<P,> SkipTrivia<P,> {
+  /// Constructs a new `SkipTrivia` from `parser`.
+  #[inline]
+  pub const fn new(parser: P,) -> Self { SkipTrivia(parser,) }
+}
+
+impl<P, T, V, E, S, I,> FnOnce<(Skipping<I, T,>,),> for SkipTrivia<P,>
+  where P: ParserFnOnce<I, Value = PResult<V, E,>,>,
+    T: ParserFn<I, Value = PResult<S, !,>,>,
+    I: Clone, {
+  type Output = Parse<PResult<V, E,>, Skipping<I, T,>,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (Skipping { input, trivia, },): (Skipping<I, T,>,),) -> Self::Output {
+    skipping(self.0.parse_once(input.clone(),), input, trivia,)
+  }
+}
+
+impl<P, T, V, E, S, I,> FnMut<(Skipping<I, T,>,),> for SkipTrivia<P,>
+  where P: ParserFnMut<I, Value = PResult<V, E,>,>,
+    T: ParserFn<I, Value = PResult<S, !,>,>,
+    I: Clone, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (Skipping { input, trivia, },): (Skipping<I, T,>,),) -> Self::Output {
+    skipping(self.0.parse_mut(input.clone(),), input, trivia,)
+  }
+}
+
+impl<P, T, V, E, S, I,> Fn<(Skipping<I, T,>,),> for SkipTrivia<P,>
+  where P: ParserFn<I, Value = PResult<V, E,>,>,
+    T: ParserFn<I, Value = PResult<S, !,>,>,
+    I: Clone, {
+  #[inline]
+  extern "rust-call" fn call(&self, (Skipping { input, trivia, },): (Skipping<I, T,>,),) -> Self::Output {
+    skipping(self.0.parse(input.clone(),), input, trivia,)
+  }
+}
+
+/// Implements the `Fn*` traits for `SkipTrivia` on inputs without trivia.
+macro_rules! impl_no_trivia {
+  ($(<$($g:ident),*> $input:ty;)*) => {$(
+    impl<'a, P, $($g,)*> FnOnce<($input,),> for SkipTrivia<P,>
+      where P: ParserFnOnce<$input,>, {
+      type Output = Parse<P::Value, $input,>;
+
+      #[inline]
+      extern "rust-call" fn call_once(self, (input,): ($input,),) -> Self::Output { self.0.parse_once(input,) }
+    }
+
+    impl<'a, P, $($g,)*> FnMut<($input,),> for SkipTrivia<P,>
+      where P: ParserFnMut<$input,>, {
+      #[inline]
+      extern "rust-call" fn call_mut(&mut self, (input,): ($input,),) -> Self::Output { self.0.parse_mut(input,) }
+    }
+
+    impl<'a, P, $($g,)*> Fn<($input,),> for SkipTrivia<P,>
+      where P: ParserFn<$input,>, {
+      #[inline]
+      extern "rust-call" fn call(&self, (input,): ($input,),) -> Self::Output { self.0.parse(input,) }
+    }
+  )*};
+}
+
+impl_no_trivia! {
+  <Y> &'a [Y];
+  <> &'a str;
+}
+
+/// A parser which applies a parser of a `Skipping` input to an input, skipping the
+/// leading trivia of the input first.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct WithTrivia<P, T,> {
+  /// The parser of the `Skipping` input.
+  pub parser: P,
+  /// The parser for the trivia.
+  pub trivia: T,
+}
+
+impl<P, T,> WithTrivia<P, T,> {
+  /// Constructs a new `WithTrivia` applying `parser` while skipping `trivia`.
+  #[inline]
+  pub const fn new(parser: P, trivia: T,) -> Self { WithTrivia { parser, trivia, } }
+}
+
+impl<P, T, V, E, S, I,> FnOnce<(I,),> for WithTrivia<P, T,>
+  where P: ParserFnOnce<Skipping<I, T,>, Value = PResult<V, E,>,>,
+    T: ParserFn<I, Value = PResult<S, !,>,>,
+    I: Clone, {
+  type Output = Parse<PResult<V, E,>, I,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output {
+    let WithTrivia { parser, trivia, } = self;
+    token(trivia.parse(input.clone(),), input, |unused,| without_trivia(parser.parse_once(Skipping::new(unused, trivia,),),),)
+  }
+}
+
+impl<P, T, V, E, S, I,> FnMut<(I,),> for WithTrivia<P, T,>
+  where P: ParserFnMut<Skipping<I, T,>, Value = PResult<V, E,>,>,
+    T: ParserFn<I, Value = PResult<S, !,>,> + Clone,
+    I: Clone, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output {
+    let (parser, trivia,) = (&mut self.parser, self.trivia.clone(),);
+    token(trivia.parse(input.clone(),), input, |unused,| without_trivia(parser.parse_mut(Skipping::new(unused, trivia,),),),)
+  }
+}
+
+impl<P, T, V, E, S, I,> Fn<(I,),> for WithTrivia<P, T,>
+  where P: ParserFn<Skipping<I, T,>, Value = PResult<V, E,>,>,
+    T: ParserFn<I, Value = PResult<S, !,>,> + Clone,
+    I: Clone, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output {
+    let trivia = self.trivia.clone();
+    token(trivia.parse(input.clone(),), input, |unused,| without_trivia(self.parser.parse(Skipping::new(unused, trivia,),),),)
+  }
+}
+
+/// Skips the trivia following a successful parse of the input of a `Skipping` input.
+fn skipping<V, E, S, I, T,>(parse: Parse<PResult<V, E,>, I,>, input: I, trivia: T,) -> Parse<PResult<V, E,>, Skipping<I, T,>,>
+  where T: ParserFn<I, Value = PResult<S, !,>,>, {
+  let Parse { value, unused, } = lexeme(parse, input, |unused,| trivia.parse(unused,),);
+  Parse::new(value, Skipping::new(unused, trivia,),)
+}
+
+/// Takes the input out of the unused `Skipping` input of a parse.
+#[inline]
+fn without_trivia<V, I, T,>(Parse { value, unused, }: Parse<V, Skipping<I, T,>,>,) -> Parse<V, I,> { Parse::new(value, unused.input,) }
+
+/// Applies `trivia` to the unused input of a successful parse.
+fn lexeme<V, E, S, I, F,>(parse: Parse<PResult<V, E,>, I,>, input: I, trivia: F,) -> Parse<PResult<V, E,>, I,>
+  where F: FnOnce(I,) -> Parse<PResult<S, !,>, I,>, {
+  let Parse { value, unused, } = parse;
+  let value = match value {
+    Output(value,) => value,
+    value => return Parse::new(value, unused,),
+  };
+
+  let Parse { value: skipped, unused, } = trivia(unused,);
+  match skipped {
+    Output(_,) => Parse::new(Output(value,), unused,),
+    Pending(pending,) => Parse::new(Pending(pending,), input,),
+    Failed(never,) => never,
+  }
+}
+
+/// Applies `parser` to the input left after the trivia.
+fn token<V, E, S, I, F,>(trivia: Parse<PResult<S, !,>, I,>, input: I, parser: F,) -> Parse<PResult<V, E,>, I,>
+  where F: FnOnce(I,) -> Parse<PResult<V, E,>, I,>, {
+  let Parse { value: skipped, unused, } = trivia;
+  match skipped {
+    Output(_,) => {},
+    Pending(pending,) => return Parse::new(Pending(pending,), input,),
+    Failed(never,) => never,
+  }
+
+  match parser(unused,) {
+    Parse { value: Output(value,), unused, } => Parse::new(Output(value,), unused,),
+    Parse { value, .. } => Parse::new(value, input,),
+  }
+}
@@ -0,0 +1,133 @@
+//! Lexing of `&str` inputs into spanned tokens which can be parsed as a slice.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+use super::sources::Trivia;
+
+/// A range of byte offsets into the input of a `Lexer`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Hash, Debug,)]
+pub struct Span {
+  /// The offset of the first byte.
+  pub start: usize,
+  /// The offset after the last byte.
+  pub end: usize,
+}
+
+impl Span {
+  /// Constructs a new `Span` from `start` to `end`.
+  #[inline]
+  pub const fn new(start: usize, end: usize,) -> Self { Span { start, end, } }
+  /// The number of bytes covered.
+  #[inline]
+  pub const fn len(&self,) -> usize { self.end - self.start }
+  /// Checks if no bytes are covered.
+  #[inline]
+  pub const fn is_empty(&self,) -> bool { self.start == self.end }
+}
+
+/// A value and the span of input it was produced from.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Hash, Debug,)]
+pub struct Spanned<T,> {
+  /// The value.
+  pub value: T,
+  /// The span of input.
+  pub span: Span,
+}
+
+impl<T,> Spanned<T,> {
+  /// Constructs a new `Spanned` from `value` and `span`.
+  #[inline]
+  pub const fn new(value: T, span: Span,) -> Self { Spanned { value, span, } }
+  /// Maps the inner `value`, keeping the span.
+  #[inline]
+  pub fn map<U, F,>(self, f: F,) -> Spanned<U,>
+    where F: FnOnce(T,) -> U, { Spanned::new(f(self.value,), self.span,) }
+}
+
+/// A token which can be matched by its kind.
+pub trait TokenKind {
+  /// The kind of the token.
+  type Kind: PartialEq;
+
+  /// The kind of this token.
+  fn kind(&self,) -> Self::Kind;
+}
+
+/// An iterator which splits a `&str` into spanned tokens.
+/// 
+/// Trivia is skipped before each token, then the token parser is applied. The trivia
+/// parser defaults to `Trivia::WHITESPACE`, if it fails nothing is skipped. Iteration stops
+/// when the input runs out or a token or the trivia is pending, the rest of the input can
+/// then be taken with `into_unused`. If a token fails the error is yielded with an empty
+/// span at the offset it failed and iteration stops.
+#[derive(Clone, Copy, Debug,)]
+pub struct Lexer<'a, P, S = Trivia,> {
+  /// The parser for the tokens.
+  parser: P,
+  /// The parser for the trivia between tokens.
+  trivia: S,
+  /// The length of the whole input.
+  len: usize,
+  /// The remaining input.
+  input: &'a str,
+  /// Whether a token has failed.
+  failed: bool,
+}
+
+impl<'a, P,> Lexer<'a, P,> {
+  /// Constructs a new `Lexer` applying `parser` to `input`, skipping whitespace between
+  /// tokens.
+  #[inline]
+  pub const fn new(parser: P, input: &'a str,) -> Self {
+    Lexer { parser, trivia: Trivia::WHITESPACE, len: input.len(), input, failed: false, }
+  }
+}
+
+impl<'a, P, S,> Lexer<'a, P, S,> {
+  /// Skips the trivia accepted by the `trivia` parser between tokens.
+  #[inline]
+  pub fn trivia<U,>(self, trivia: U,) -> Lexer<'a, P, U,> {
+    let Lexer { parser, len, input, failed, .. } = self;
+    Lexer { parser, trivia, len, input, failed, }
+  }
+  /// The offset of the remaining input.
+  #[inline]
+  pub const fn offset(&self,) -> usize { self.len - self.input.len() }
+  /// The input which has not been split into tokens.
+  #[inline]
+  pub const fn unused(&self,) -> &'a str { self.input }
+  /// Returns the input which has not been split into tokens.
+  #[inline]
+  pub fn into_unused(self,) -> &'a str { self.input }
+}
+
+impl<'a, P, S, T, E, U, F,> Iterator for Lexer<'a, P, S,>
+  where P: ParserFnMut<&'a str, Value = PResult<T, E,>,>,
+    S: ParserFnMut<&'a str, Value = PResult<U, F,>,>, {
+  type Item = PResult<Spanned<T,>, Spanned<E,>,>;
+
+  fn next(&mut self,) -> Option<Self::Item> {
+    if self.failed { return None }
+
+    let rest = match self.trivia.parse_mut(self.input,) {
+      Parse { value: Output(_,), unused, } => unused,
+      Parse { value: Failed(_,), .. } => self.input,
+      Parse { value: Pending(_,), .. } => return None,
+    };
+    let start = self.len - rest.len();
+    match self.parser.parse_mut(rest,) {
+      Parse { value: Output(token,), unused, } => {
+        self.input = unused;
+        Some(Output(Spanned::new(token, Span::new(start, self.offset(),),),),)
+      },
+      Parse { value: Failed(error,), .. } => {
+        self.input = rest;
+        self.failed = true;
+        Some(Failed(Spanned::new(error, Span::new(start, start,),),),)
+      },
+      Parse { value: Pending(_,), .. } => None,
+    }
+  }
+}
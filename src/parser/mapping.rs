@@ -1,9 +1,11 @@
 //! Transformers of the output type of a parser by mapping the output value.
 //! 
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
 use crate::{*, combinators::{TryMap, TryMapErr,},};
+#[cfg(not(feature = "nightly",),)]
+use crate::stable::StableParse;
 
 /// A parser which maps the successful output value of the inner parser.
 pub type MapOk<F, P,> = Map<TryMap<F,>, P,>;
@@ -14,9 +16,9 @@ pub type MapErr<F, P,> = Map<TryMapErr<F,>, P,>;
 #[derive(Clone, Copy, Default, Debug,)]
 pub struct Map<F, P,> {
   /// The mapping to apply.
-  map: F,
+  pub(crate) map: F,
   /// The parser to map.
-  parser: P,
+  pub(crate) parser: P,
 }
 
 impl<F, P,> Map<F, P,> {
@@ -25,6 +27,7 @@ impl<F, P,> Map<F, P,> {
   pub const fn new(map: F, parser: P,) -> Self { Self { map, parser, } }
 }
 
+#[cfg(feature = "nightly",)]
 impl<F, P, T, I,> FnOnce<(I,),> for Map<F, P,>
   where F: FnOnce(P::Value,) -> T,
     P: ParserFnOnce<I,>, {
@@ -36,6 +39,7 @@ impl<F, P, T, I,> FnOnce<(I,),> for Map<F, P,>
   }
 }
 
+#[cfg(feature = "nightly",)]
 impl<F, P, T, I,> FnMut<(I,),> for Map<F, P,>
   where F: FnMut(P::Value,) -> T,
     P: ParserFnMut<I,>, {
@@ -45,6 +49,7 @@ impl<F, P, T, I,> FnMut<(I,),> for Map<F, P,>
   }
 }
 
+#[cfg(feature = "nightly",)]
 impl<F, P, T, I,> Fn<(I,),> for Map<F, P,>
   where F: Fn(P::Value,) -> T,
     P: ParserFn<I,>, {
@@ -53,3 +58,37 @@ impl<F, P, T, I,> Fn<(I,),> for Map<F, P,>
     self.parser.parse(input,).map(&self.map,)
   }
 }
+
+#[cfg(not(feature = "nightly",),)]
+impl<F, P, T, I,> StableParse<I,> for Map<F, P,>
+  where F: Fn(P::Value,) -> T,
+    P: StableParse<I,>, {
+  type Value = T;
+
+  #[inline]
+  fn parse(&self, input: I,) -> Parse<Self::Value, I,> { self.parser.parse(input,).map(&self.map,) }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<F, P, T, U, E, I,> StableParse<I,> for MapOk<F, P,>
+  where F: Fn(T,) -> U,
+    P: StableParse<I, Value = PResult<T, E,>,>, {
+  type Value = PResult<U, E,>;
+
+  #[inline]
+  fn parse(&self, input: I,) -> Parse<Self::Value, I,> {
+    self.parser.parse(input,).map(|value,| value.map(&self.map.0,),)
+  }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<F, P, T, E, U, I,> StableParse<I,> for MapErr<F, P,>
+  where F: Fn(E,) -> U,
+    P: StableParse<I, Value = PResult<T, E,>,>, {
+  type Value = PResult<T, U,>;
+
+  #[inline]
+  fn parse(&self, input: I,) -> Parse<Self::Value, I,> {
+    self.parser.parse(input,).map(|value,| value.map_fail(&self.map.0,),)
+  }
+}
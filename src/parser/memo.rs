@@ -0,0 +1,189 @@
+//! Packrat memoisation of the rules of a grammar.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{*, parser::Remaining,};
+use core::cell::{Cell, RefCell,};
+use alloc::collections::BTreeMap;
+
+/// The statistics of a `MemoTable`.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct MemoStats {
+  /// The number of parses answered from the table.
+  pub hits: usize,
+  /// The number of parses which ran their rule.
+  pub misses: usize,
+  /// The number of parses currently in the table.
+  pub entries: usize,
+}
+
+impl MemoStats {
+  /// The fraction of parses answered from the table.
+  pub fn hit_rate(&self,) -> f64 {
+    match self.hits + self.misses {
+      0 => 0.0,
+      total => self.hits as f64 / total as f64,
+    }
+  }
+}
+
+/// A table of the parses of memoised rules, keyed by rule and input offset.
+/// 
+/// A table is scoped to a single input, it must be cleared before it is used with another
+/// input or the parses of the old input will be returned. Rules sharing a table must
+/// produce the same value.
+/// 
+/// When the table holds more parses than its limit the parses furthest from the end of
+/// the input are evicted first.
+#[derive(Debug,)]
+pub struct MemoTable<V, I,> {
+  /// The cached parses keyed by the remaining input length and the rule.
+  entries: RefCell<BTreeMap<(usize, usize,), Parse<V, I,>,>,>,
+  /// The maximum number of parses held.
+  limit: Cell<Option<usize>>,
+  /// The id of the next rule.
+  rules: Cell<usize>,
+  /// The number of parses answered from the table.
+  hits: Cell<usize>,
+  /// The number of parses which ran their rule.
+  misses: Cell<usize>,
+}
+
+impl<V, I,> MemoTable<V, I,> {
+  /// Constructs a new, empty `MemoTable` without a limit.
+  #[inline]
+  pub fn new() -> Self {
+    MemoTable {
+      entries: RefCell::new(BTreeMap::new(),),
+      limit: Cell::new(None,),
+      rules: Cell::new(0,),
+      hits: Cell::new(0,),
+      misses: Cell::new(0,),
+    }
+  }
+  /// Constructs a new, empty `MemoTable` holding at most `limit` parses.
+  #[inline]
+  pub fn with_limit(limit: usize,) -> Self {
+    let table = Self::new();
+    table.limit.set(Some(limit,),);
+    table
+  }
+  /// Sets the maximum number of parses held, evicting parses as needed.
+  pub fn set_limit(&self, limit: Option<usize>,) {
+    self.limit.set(limit,);
+    self.evict();
+  }
+  /// Removes every parse from the table.
+  #[inline]
+  pub fn clear(&self,) { self.entries.borrow_mut().clear() }
+  /// Removes the parses of offsets before `input`.
+  /// 
+  /// Parsers which have committed to `input` will not backtrack before it so those parses
+  /// cannot be used again.
+  pub fn discard(&self, input: &I,)
+    where I: Remaining, {
+    let mut entries = self.entries.borrow_mut();
+    let _ = entries.split_off(&(input.remaining() + 1, 0,),);
+  }
+  /// The statistics of the table.
+  #[inline]
+  pub fn stats(&self,) -> MemoStats {
+    MemoStats { hits: self.hits.get(), misses: self.misses.get(), entries: self.entries.borrow().len(), }
+  }
+  /// Resets the hit and miss counts.
+  #[inline]
+  pub fn reset_stats(&self,) {
+    self.hits.set(0,);
+    self.misses.set(0,);
+  }
+  /// Allocates a new rule id.
+  #[inline]
+  fn rule(&self,) -> usize {
+    let rule = self.rules.get();
+    self.rules.set(rule + 1,);
+    rule
+  }
+  /// Evicts the parses furthest from the end of the input until the table is in its limit.
+  fn evict(&self,) {
+    let limit = match self.limit.get() {
+      Some(limit,) => limit,
+      None => return,
+    };
+
+    let mut entries = self.entries.borrow_mut();
+    while entries.len() > limit {
+      let key = match entries.keys().next_back() {
+        Some(&key,) => key,
+        None => break,
+      };
+      entries.remove(&key,);
+    }
+  }
+}
+
+impl<V, I,> Default for MemoTable<V, I,> {
+  #[inline]
+  fn default() -> Self { Self::new() }
+}
+
+/// A parser which caches its parses in a `MemoTable`.
+/// 
+/// Each `Memo` constructed from a table is a distinct rule, copies of a `Memo` are the
+/// same rule. The inner parser is only run the first time the rule is applied at an
+/// offset, later applications return a clone of the first parse.
+#[derive(Clone, Copy, Debug,)]
+pub struct Memo<'t, P, V, I,> {
+  /// The parser for the rule.
+  pub parser: P,
+  /// The id of the rule.
+  rule: usize,
+  /// The table of parses.
+  table: &'t MemoTable<V, I,>,
+}
+
+impl<'t, P, V, I,> Memo<'t, P, V, I,> {
+  /// Constructs a new `Memo` rule caching the parses of `parser` in `table`.
+  #[inline]
+  pub fn new(parser: P, table: &'t MemoTable<V, I,>,) -> Self { Memo { parser, rule: table.rule(), table, } }
+}
+
+impl<'t, P, V, I,> FnOnce<(I,),> for Memo<'t, P, V, I,>
+  where P: ParserFn<I, Value = V,>,
+    V: Clone,
+    I: Remaining + Clone, {
+  type Output = Parse<V, I,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'t, P, V, I,> FnMut<(I,),> for Memo<'t, P, V, I,>
+  where P: ParserFn<I, Value = V,>,
+    V: Clone,
+    I: Remaining + Clone, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'t, P, V, I,> Fn<(I,),> for Memo<'t, P, V, I,>
+  where P: ParserFn<I, Value = V,>,
+    V: Clone,
+    I: Remaining + Clone, {
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output {
+    let key = (input.remaining(), self.rule,);
+    //The borrow must end before the rule runs as it may apply other memoised rules.
+    let cached = self.table.entries.borrow().get(&key,).cloned();
+    if let Some(parse,) = cached {
+      self.table.hits.set(self.table.hits.get() + 1,);
+      return parse
+    }
+
+    self.table.misses.set(self.table.misses.get() + 1,);
+    let parse = self.parser.parse(input,);
+    self.table.entries.borrow_mut().insert(key, parse.clone(),);
+    self.table.evict();
+
+    parse
+  }
+}
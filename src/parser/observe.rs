@@ -0,0 +1,255 @@
+//! Event hooks for profiling named rules.
+//! 
+//! An [`Observe`](self::Observe) rule reports to its [`ParseObserver`](self::ParseObserver)
+//! when it is entered and exited, and when it fails handing its input back to be tried
+//! by another alternative.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{*, parser::Remaining,};
+#[cfg(feature = "alloc",)]
+use core::cell::RefCell;
+#[cfg(feature = "alloc",)]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std",)]
+use std::{time::{Duration, Instant,}, vec::Vec,};
+
+/// An observer of the events of named rules.
+/// 
+/// Every `enter` is followed by an `exit` of the same rule, events of rules applied by
+/// the rule are nested between them.
+pub trait ParseObserver {
+  /// Called when `rule` is applied.
+  #[inline]
+  fn enter(&self, _rule: &'static str,) {}
+  /// Called when `rule` fails, before it exits.
+  #[inline]
+  fn backtrack(&self, _rule: &'static str,) {}
+  /// Called when `rule` returns having consumed `consumed` tokens.
+  #[inline]
+  fn exit(&self, _rule: &'static str, _consumed: usize,) {}
+}
+
+impl<O,> ParseObserver for &'_ O
+  where O: ParseObserver + ?Sized, {
+  #[inline]
+  fn enter(&self, rule: &'static str,) { (**self).enter(rule,) }
+  #[inline]
+  fn backtrack(&self, rule: &'static str,) { (**self).backtrack(rule,) }
+  #[inline]
+  fn exit(&self, rule: &'static str, consumed: usize,) { (**self).exit(rule, consumed,) }
+}
+
+impl<A, B,> ParseObserver for (A, B,)
+  where A: ParseObserver,
+    B: ParseObserver, {
+  #[inline]
+  fn enter(&self, rule: &'static str,) {
+    self.0.enter(rule,);
+    self.1.enter(rule,);
+  }
+  #[inline]
+  fn backtrack(&self, rule: &'static str,) {
+    self.0.backtrack(rule,);
+    self.1.backtrack(rule,);
+  }
+  #[inline]
+  fn exit(&self, rule: &'static str, consumed: usize,) {
+    self.0.exit(rule, consumed,);
+    self.1.exit(rule, consumed,);
+  }
+}
+
+/// A value which can report the failure of a parse.
+pub trait Outcome {
+  /// Checks if this value is a failure.
+  fn is_failed(&self,) -> bool;
+}
+
+impl<T, E,> Outcome for PResult<T, E,> {
+  #[inline]
+  fn is_failed(&self,) -> bool { PResult::is_failed(self,) }
+}
+
+impl<T, E,> Outcome for Result<T, E,> {
+  #[inline]
+  fn is_failed(&self,) -> bool { self.is_err() }
+}
+
+impl<T,> Outcome for Option<T,> {
+  #[inline]
+  fn is_failed(&self,) -> bool { self.is_none() }
+}
+
+/// A parser which reports the application of the inner parser as a named rule.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Observe<P, O,> {
+  /// The name of the rule.
+  pub name: &'static str,
+  /// The parser for the rule.
+  pub parser: P,
+  /// The observer of the rule.
+  pub observer: O,
+}
+
+impl<P, O,> Observe<P, O,> {
+  /// Constructs a new `Observe` reporting `parser` as the rule `name` to `observer`.
+  #[inline]
+  pub const fn new(name: &'static str, parser: P, observer: O,) -> Self { Observe { name, parser, observer, } }
+}
+
+impl<P, O, I,> FnOnce<(I,),> for Observe<P, O,>
+  where P: ParserFnOnce<I,>,
+    P::Value: Outcome,
+    O: ParseObserver,
+    I: Remaining, {
+  type Output = Parse<P::Value, I,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output {
+    let parser = self.parser;
+    observe(self.name, &self.observer, input, |input,| parser.parse_once(input,),)
+  }
+}
+
+impl<P, O, I,> FnMut<(I,),> for Observe<P, O,>
+  where P: ParserFnMut<I,>,
+    P::Value: Outcome,
+    O: ParseObserver,
+    I: Remaining, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output {
+    let parser = &mut self.parser;
+    observe(self.name, &self.observer, input, |input,| parser.parse_mut(input,),)
+  }
+}
+
+impl<P, O, I,> Fn<(I,),> for Observe<P, O,>
+  where P: ParserFn<I,>,
+    P::Value: Outcome,
+    O: ParseObserver,
+    I: Remaining, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output {
+    observe(self.name, &self.observer, input, |input,| self.parser.parse(input,),)
+  }
+}
+
+/// Applies `parser` to `input`, reporting it to `observer` as the rule `name`.
+fn observe<V, O, I, F,>(name: &'static str, observer: &O, input: I, parser: F,) -> Parse<V, I,>
+  where F: FnOnce(I,) -> Parse<V, I,>,
+    V: Outcome,
+    O: ParseObserver,
+    I: Remaining, {
+  let remaining = input.remaining();
+  observer.enter(name,);
+  let parse = parser(input,);
+  if parse.value.is_failed() { observer.backtrack(name,) }
+  observer.exit(name, remaining.saturating_sub(parse.unused.remaining(),),);
+
+  parse
+}
+
+/// The counts of a rule recorded by a `CountingObserver`.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct RuleCounts {
+  /// The number of times the rule was applied.
+  pub invocations: usize,
+  /// The number of times the rule failed.
+  pub backtracks: usize,
+  /// The total number of tokens consumed by the rule.
+  pub consumed: usize,
+}
+
+/// An observer which counts the invocations, backtracks and tokens consumed of each rule.
+#[cfg(feature = "alloc",)]
+#[derive(Clone, Default, Debug,)]
+pub struct CountingObserver {
+  /// The counts of each rule.
+  counts: RefCell<BTreeMap<&'static str, RuleCounts,>,>,
+}
+
+#[cfg(feature = "alloc",)]
+impl CountingObserver {
+  /// Constructs a new `CountingObserver` without any counts.
+  #[inline]
+  pub fn new() -> Self { Self::default() }
+  /// The counts of `rule`.
+  #[inline]
+  pub fn get(&self, rule: &str,) -> Option<RuleCounts> { self.counts.borrow().get(rule,).copied() }
+  /// The counts of every rule which has been applied.
+  #[inline]
+  pub fn counts(&self,) -> BTreeMap<&'static str, RuleCounts,> { self.counts.borrow().clone() }
+  /// Removes all counts.
+  #[inline]
+  pub fn clear(&self,) { self.counts.borrow_mut().clear() }
+}
+
+#[cfg(feature = "alloc",)]
+impl ParseObserver for CountingObserver {
+  #[inline]
+  fn enter(&self, rule: &'static str,) { self.counts.borrow_mut().entry(rule,).or_default().invocations += 1 }
+  #[inline]
+  fn backtrack(&self, rule: &'static str,) { self.counts.borrow_mut().entry(rule,).or_default().backtracks += 1 }
+  #[inline]
+  fn exit(&self, rule: &'static str, consumed: usize,) {
+    self.counts.borrow_mut().entry(rule,).or_default().consumed += consumed
+  }
+}
+
+/// The time spent in a rule recorded by a `TimingObserver`.
+#[cfg(feature = "std",)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct RuleTime {
+  /// The number of times the rule returned.
+  pub calls: usize,
+  /// The total time spent in the rule, including the rules it applied.
+  pub total: Duration,
+}
+
+/// An observer which times each rule.
+#[cfg(feature = "std",)]
+#[derive(Clone, Default, Debug,)]
+pub struct TimingObserver {
+  /// The times each open rule was entered.
+  entered: RefCell<Vec<Instant>>,
+  /// The time spent in each rule.
+  times: RefCell<BTreeMap<&'static str, RuleTime,>,>,
+}
+
+#[cfg(feature = "std",)]
+impl TimingObserver {
+  /// Constructs a new `TimingObserver` without any times.
+  #[inline]
+  pub fn new() -> Self { Self::default() }
+  /// The time spent in `rule`.
+  #[inline]
+  pub fn get(&self, rule: &str,) -> Option<RuleTime> { self.times.borrow().get(rule,).copied() }
+  /// The time spent in every rule which has returned.
+  #[inline]
+  pub fn times(&self,) -> BTreeMap<&'static str, RuleTime,> { self.times.borrow().clone() }
+  /// Removes all times and forgets the rules which are still open.
+  #[inline]
+  pub fn clear(&self,) {
+    self.entered.borrow_mut().clear();
+    self.times.borrow_mut().clear()
+  }
+}
+
+#[cfg(feature = "std",)]
+impl ParseObserver for TimingObserver {
+  #[inline]
+  fn enter(&self, _rule: &'static str,) { self.entered.borrow_mut().push(Instant::now(),) }
+  fn exit(&self, rule: &'static str, _consumed: usize,) {
+    let entered = match self.entered.borrow_mut().pop() {
+      Some(entered,) => entered,
+      None => return,
+    };
+
+    let mut times = self.times.borrow_mut();
+    let time = times.entry(rule,).or_default();
+    time.calls += 1;
+    time.total += entered.elapsed();
+  }
+}
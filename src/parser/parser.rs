@@ -1,15 +1,16 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
-use super::{*, sources::*, mapping::*, sequence::*,};
+use super::{*, sources::*, mapping::*, sequence::*, bits::*, framing::*, lexeme::*, pratt::*, state::*, indent::*, lexer::*, observe::*, iter::*, repeat::*,};
+#[cfg(feature = "alloc",)]
+use {crate::{Parse, PResult,}, super::{memo::*, left_rec::*,},};
+#[cfg(any(feature = "std", feature = "log",),)]
+use super::trace::*;
 use crate::combinators::{
   Pair, And, AndOk, Or, TryMap, TryMapErr, SeqApply, SeqApplyOk, SeqApplyErr, SeqPipe,
   TrySeq, TrySeqErr,
 };
-use core::{
-  ops::Try,
-  convert::{AsRef, AsMut,},
-};
+use core::convert::{AsRef, AsMut,};
 
 /// An adaptor for parsers which provideds const methods for chaining and transforming
 /// parsers.
@@ -122,15 +123,90 @@ impl<T,> Parser<Tag<T,>,> {
     where Self: ParserFnOnce<I,>, { Self::new(Tag::new(tag,),) }
 }
 
+impl<T,> Parser<TagNoCase<T,>,> {
+  /// Constructs a new parser which accepts a specific sequence of tokens ignoring case.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::tag_no_case::<&[u8]>("Content-Type");
+  /// assert_eq!(parser.parse(&b"content-type: x"[..]), (Output(&b"content-type"[..]), &b": x"[..]));
+  /// assert_eq!(parser.parse("CONTENT-LENGTH"), (Failed("CONTENT-"), "CONTENT-LENGTH"));
+  /// let parser = Parser::tag_no_case::<&str>("σίσυφος");
+  /// assert_eq!(parser.parse("ΣΊΣΥΦΟΣ"), (Output("ΣΊΣΥΦΟΣ"), ""));
+  /// let parser = Parser::tag_no_case::<&str>("kiwi");
+  /// assert_eq!(parser.parse("\u{212A}IWI"), (Output("\u{212A}IWI"), ""));
+  /// assert_eq!(parser.parse("K\u{130}WI"), (Failed("K"), "K\u{130}WI"));
+  /// ```
+  #[inline]
+  pub const fn tag_no_case<I,>(tag: T,) -> Self
+    where Self: ParserFnOnce<I,>, { Self::new(TagNoCase::new(tag,),) }
+}
+
+#[cfg(feature = "nfc",)]
+impl<T,> Parser<TagNoCaseNfc<T,>,> {
+  /// Constructs a new parser which accepts a specific sequence of characters ignoring
+  /// case and normalisation.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::tag_no_case_nfc("Café");
+  /// assert_eq!(parser.parse("CAFE\u{301} au lait"), (Output("CAFE\u{301}"), " au lait"));
+  /// assert_eq!(parser.parse("cafe\u{301}"), (Pending(1), "cafe\u{301}"));
+  /// let parser = Parser::tag_no_case_nfc("한국");
+  /// assert_eq!(parser.parse("\u{1112}\u{1161}\u{11ab}국어"), (Output("\u{1112}\u{1161}\u{11ab}국"), "어"));
+  /// ```
+  #[inline]
+  pub const fn tag_no_case_nfc(tag: T,) -> Self { Self::new(TagNoCaseNfc::new(tag,),) }
+}
+
+impl<T,> Parser<KeywordSet<T,>,> {
+  /// Constructs a new parser which accepts the longest of a set of keywords and outputs it
+  /// with its associated value.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::tags::<_, _, &str>([("=", 0), ("==", 1), ("=>", 2), ("!=", 3)]);
+  /// assert_eq!(parser.parse("== x"), (Output(("==", 1)), " x"));
+  /// assert_eq!(parser.parse("= x"), (Output(("=", 0)), " x"));
+  /// assert_eq!(parser.parse("="), (Pending(1), "="));
+  /// assert_eq!(parser.parse("!x"), (Failed("!"), "!x"));
+  /// ```
+  #[inline]
+  pub fn tags<K, V, I,>(keywords: T,) -> Self
+    where T: AsMut<[(K, V,)]>,
+      K: Ord,
+      Self: ParserFnOnce<I,>, { Self::new(KeywordSet::new(keywords,),) }
+  /// Constructs a new parser which accepts the longest of a set of keywords which are
+  /// already sorted.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::sources::KeywordSet,};
+  /// 
+  /// const KEYWORDS: Parser<KeywordSet<&[(&str, u8)]>> = Parser::sorted_tags(&[("else", 0), ("if", 1)]);
+  /// assert_eq!(KEYWORDS.parse("if x"), (Output(("if", 1)), " x"));
+  /// ```
+  #[inline]
+  pub const fn sorted_tags(keywords: T,) -> Self { Self::new(KeywordSet::sorted(keywords,),) }
+}
+
 impl<T,> Parser<OneOf<T,>,> {
   /// Constructs a new parser which accepts the next token using a set of allowed tokens.
   /// 
   /// ```
-  /// use ::parser::*;
+  /// use ::parser::{*, parser::sources::{ByteClass, CharClass,},};
   /// 
   /// let parser = Parser::one_of("ad");
   /// assert_eq!(parser.parse(&b"abc"[..]), (Output(b"a"), &b"bc"[..]));
   /// assert_eq!(parser.parse(&b"dbc"[..]), (Output(b"d"), &b"bc"[..]));
+  /// let parser = Parser::one_of(ByteClass::HEX_DIGIT);
+  /// assert_eq!(parser.parse(&b"fbc"[..]), (Output(b"f"), &b"bc"[..]));
+  /// let parser = Parser::one_of::<&str>(CharClass::WHITESPACE);
+  /// assert_eq!(parser.parse("\u{3000}bc"), (Output('\u{3000}'), "bc"));
+  /// let parser = Parser::one_of::<&str>(CharClass::SYMBOL);
+  /// assert_eq!(parser.parse("€5"), (Output('€'), "5"));
   /// ```
   #[inline]
   pub const fn one_of<I,>(one_of: T,) -> Self
@@ -172,18 +248,381 @@ impl<F,> Parser<Sat1<F,>,> {
   /// Constructs a new parser which accepts tokens as long as they satisfy a predicate.
   /// 
   /// ```
-  /// use ::parser::*;
+  /// use ::parser::{*, parser::sources::UnicodeClass,};
   /// 
   /// let pred = |_, &t| t != b'c';
   /// let parser = Parser::sat1(&pred);
   /// assert_eq!(parser.parse(&b"abc"[..]), (Output(&b"ab"[..]), &b"c"[..]));
   /// assert_eq!(parser.parse(&b"c"[..]), (Failed(b"c"), &b"c"[..]));
+  /// let parser = Parser::sat1::<&str>(UnicodeClass::Alphabetic);
+  /// assert_eq!(parser.parse("héllo wörld"), (Output("héllo"), " wörld"));
+  /// let parser = Parser::sat1::<&str>(UnicodeClass::Punctuation);
+  /// assert_eq!(parser.parse("¿«¡»? €5"), (Output("¿«¡»?"), " €5"));
   /// ```
   #[inline]
   pub const fn sat1<I,>(pred: F,) -> Self
     where Self: ParserFnOnce<I,>, { Self::new(Sat1::new(pred,),) }
 }
 
+impl<T,> Parser<TakeUntil<T,>,> {
+  /// Constructs a new parser which accepts tokens up to the first occurrence of `tag`.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::take_until::<&[u8]>("\r\n");
+  /// assert_eq!(parser.parse(&b"abc\r\nd"[..]), (Output(&b"abc"[..]), &b"\r\nd"[..]));
+  /// assert_eq!(parser.parse(&b"abc\r"[..]), (Pending(1), &b"abc\r"[..]));
+  /// assert_eq!(parser.parse("abc"), (Pending(2), "abc"));
+  /// ```
+  #[inline]
+  pub const fn take_until<I,>(tag: T,) -> Self
+    where Self: ParserFnOnce<I,>, { Self::new(TakeUntil::new(tag,),) }
+}
+
+impl<F,> Parser<TakeTill<F,>,> {
+  /// Constructs a new parser which accepts tokens until one satisfies a predicate.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let pred = |c: char| c == '"';
+  /// let parser = Parser::take_till(&pred);
+  /// assert_eq!(parser.parse("abc\"d"), (Output("abc"), "\"d"));
+  /// assert_eq!(parser.parse("abc"), (Pending(1), "abc"));
+  /// ```
+  #[inline]
+  pub const fn take_till<I,>(pred: F,) -> Self
+    where Self: ParserFnOnce<I,>, { Self::new(TakeTill::new(pred,),) }
+}
+
+impl<T,> Parser<TakeUntilAny<T,>,> {
+  /// Constructs a new parser which accepts tokens up to the first token in `set`.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::take_until_any::<&[u8]>(",;");
+  /// assert_eq!(parser.parse(&b"abc;d"[..]), (Output(&b"abc"[..]), &b";d"[..]));
+  /// assert_eq!(parser.parse("ab,c;d"), (Output("ab"), ",c;d"));
+  /// ```
+  #[inline]
+  pub const fn take_until_any<I,>(set: T,) -> Self
+    where Self: ParserFnOnce<I,>, { Self::new(TakeUntilAny::new(set,),) }
+}
+
+#[cfg(feature = "regex",)]
+impl Parser<Regex,> {
+  /// Constructs a new parser which accepts the match of a regex anchored at the front of
+  /// the input.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::regex(r"\d+\.\d+").unwrap();
+  /// assert_eq!(parser.parse("1.22 x"), (Output("1.22"), " x"));
+  /// assert_eq!(parser.parse("1.22"), (Pending(1), "1.22"));
+  /// assert_eq!(parser.parse(&b"1.x"[..]), (Failed(&b"1."[..]), &b"1.x"[..]));
+  /// ```
+  #[inline]
+  pub fn regex(pattern: &str,) -> Result<Self, RegexError> { Regex::new(pattern,).map(Self::new,) }
+}
+
+#[cfg(feature = "regex",)]
+impl Parser<RegexCaptures,> {
+  /// Constructs a new parser which accepts the match of a regex anchored at the front of
+  /// the input and outputs its capture groups.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::regex_captures(r"(?<major>\d+)\.(?<minor>\d+)").unwrap();
+  /// let captures = parser.parse("1.22 x").value.unwrap();
+  /// assert_eq!(captures.name("major"), Some("1"));
+  /// assert_eq!(captures.name("minor"), Some("22"));
+  /// ```
+  #[inline]
+  pub fn regex_captures(pattern: &str,) -> Result<Self, RegexError> {
+    RegexCaptures::new(pattern,).map(Self::new,)
+  }
+}
+
+impl Parser<Trivia,> {
+  /// Constructs a new parser which skips whitespace and comments.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::sources::Trivia,};
+  /// 
+  /// let parser = Parser::trivia(Trivia::WHITESPACE.line_comment("#"));
+  /// assert_eq!(parser.parse("  # note\n x"), (Output("  # note\n "), "x"));
+  /// assert_eq!(parser.parse("  "), (Pending(1), "  "));
+  /// ```
+  #[inline]
+  pub const fn trivia(trivia: Trivia,) -> Self { Self::new(trivia,) }
+}
+
+impl Parser<StringLit,> {
+  /// Constructs a new parser which accepts a quoted string literal.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::sources::{StringLit, Literal, LiteralError, EscapeError,},};
+  /// 
+  /// let parser = Parser::string_lit(StringLit::DOUBLE.raw());
+  /// assert_eq!(parser.parse(r#""abc" x"#), (Output(Literal::Borrowed("abc")), " x"));
+  /// assert_eq!(parser.parse(r##"r#"a "b" c"# x"##), (Output(Literal::Borrowed(r#"a "b" c"#)), " x"));
+  /// assert_eq!(parser.parse(r#""a\tb"#), (Pending(1), r#""a\tb"#));
+  /// assert_eq!(parser.parse(r#""a\qb""#), (Failed(LiteralError::Escape(2, EscapeError::Unknown('q'))), r#""a\qb""#));
+  /// let literal = parser.parse(r#""a\u{e9}b""#).value.unwrap();
+  /// assert!(literal.chars().eq("aéb".chars()));
+  /// ```
+  #[inline]
+  pub const fn string_lit(literal: StringLit,) -> Self { Self::new(literal,) }
+  /// Unescapes the literals accepted by this parser.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::sources::StringLit,};
+  /// 
+  /// let parser = Parser::string_lit(StringLit::SINGLE).unescaped();
+  /// assert_eq!(parser.parse(r"'a\nb'").value.unwrap(), "a\nb");
+  /// ```
+  #[cfg(feature = "alloc",)]
+  #[inline]
+  pub const fn unescaped(self,) -> Parser<Unescaped,> { Parser::new(Unescaped(self.0,),) }
+}
+
+impl Parser<LineEnding,> {
+  /// Constructs a new parser which accepts a `\n` or `\r\n` line ending.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::line_ending();
+  /// assert_eq!(parser.parse("\r\nabc"), (Output("\r\n"), "abc"));
+  /// assert_eq!(parser.parse(&b"\r"[..]), (Pending(1), &b"\r"[..]));
+  /// assert_eq!(parser.parse("abc"), (Failed('a'), "abc"));
+  /// ```
+  #[inline]
+  pub const fn line_ending() -> Self { Self::new(LineEnding::new(),) }
+}
+
+impl Parser<NotLineEnding,> {
+  /// Constructs a new parser which accepts tokens up to the next `\n` or `\r\n` line
+  /// ending.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::not_line_ending();
+  /// assert_eq!(parser.parse("a\rb\r\nc"), (Output("a\rb"), "\r\nc"));
+  /// assert_eq!(parser.parse("abc\r"), (Pending(1), "abc\r"));
+  /// ```
+  #[inline]
+  pub const fn not_line_ending() -> Self { Self::new(NotLineEnding::new(),) }
+}
+
+impl Parser<TerminatedLine,> {
+  /// Constructs a new parser which accepts a line ending in `\n` or `\r\n` and outputs
+  /// it without its line ending.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::sources::TerminatedLine,};
+  /// 
+  /// let parser = Parser::terminated_line();
+  /// assert_eq!(parser.parse(&b"GET / HTTP/1.1\r\nHost"[..]), (Output(&b"GET / HTTP/1.1"[..]), &b"Host"[..]));
+  /// let parser = Parser::new(TerminatedLine::new().lone_cr());
+  /// assert_eq!(parser.parse("abc\rdef"), (Output("abc"), "def"));
+  /// ```
+  #[inline]
+  pub const fn terminated_line() -> Self { Self::new(TerminatedLine::new(),) }
+}
+
+impl<A, Pre, In, Post,> Parser<PrattParser<A, Pre, In, Post,>,> {
+  /// Constructs a new parser for expressions built from atoms and tables of operators.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::{sources::{OneOf, Tag,}, pratt::*,},};
+  /// 
+  /// const NEG: &[Prefix<Tag<&str>, fn(i32) -> i32>] = &[Prefix::new(Tag::new("-"), 5, |a| -a)];
+  /// let infix = (
+  ///   Infix::new(OneOf::new("+-"), 1, Assoc::Left, |a: i32, b| a + b),
+  ///   Infix::new(Tag::new("*"), 2, Assoc::Left, |a: i32, b| a * b),
+  ///   Infix::new(Tag::new("^"), 3, Assoc::Right, |a: i32, b| a.pow(b as u32)),
+  /// );
+  /// let postfix = Postfix::new(Tag::new("!"), 4, |a: i32| (1..=a).product());
+  /// let value = |&[d]: &[u8; 1]| (d - b'0') as i32;
+  /// let digit = Parser::one_of("0123456789").map_ok(&value);
+  /// let parser = Parser::pratt(PrattParser::new(digit).prefix(NEG).infix(infix).postfix(postfix));
+  /// assert_eq!(parser.parse(&b"1+2*3;"[..]), (Output(7), &b";"[..]));
+  /// assert_eq!(parser.parse(&b"2^3^2;"[..]), (Output(512), &b";"[..]));
+  /// assert_eq!(parser.parse(&b"3!*2;"[..]), (Output(12), &b";"[..]));
+  /// assert_eq!(parser.parse(&b"-2*3;"[..]), (Output(-6), &b";"[..]));
+  /// assert_eq!(parser.parse(&b"1+2"[..]), (Pending(1), &b"1+2"[..]));
+  /// ```
+  #[inline]
+  pub const fn pratt(pratt: PrattParser<A, Pre, In, Post,>,) -> Self { Self::new(pratt,) }
+}
+
+impl Parser<ULeb128,> {
+  /// Constructs a new parser which accepts an unsigned LEB128 encoded integer.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::uleb128();
+  /// assert_eq!(parser.parse(&[0xe5, 0x8e, 0x26, 0x00][..]), (Output(624485), &[0x00][..]));
+  /// assert_eq!(parser.parse(&[0xe5, 0x8e][..]), (Pending(1), &[0xe5, 0x8e][..]));
+  /// ```
+  #[inline]
+  pub const fn uleb128() -> Self { Self::new(ULeb128,) }
+  /// Constructs a new parser which accepts a protobuf style varint.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::varint();
+  /// assert_eq!(parser.parse(&[0xac, 0x02][..]), (Output(300), &b""[..]));
+  /// let overflow = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02];
+  /// assert_eq!(parser.parse(&overflow[..]), (Failed(&overflow[..]), &overflow[..]));
+  /// ```
+  #[inline]
+  pub const fn varint() -> Self { Self::new(ULeb128,) }
+}
+
+impl Parser<SLeb128,> {
+  /// Constructs a new parser which accepts a signed LEB128 encoded integer.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::sleb128();
+  /// assert_eq!(parser.parse(&[0xc0, 0xbb, 0x78][..]), (Output(-123456), &b""[..]));
+  /// assert_eq!(parser.parse(&[0x3f][..]), (Output(63), &b""[..]));
+  /// ```
+  #[inline]
+  pub const fn sleb128() -> Self { Self::new(SLeb128,) }
+}
+
+impl Parser<ZigZag,> {
+  /// Constructs a new parser which accepts a protobuf style zigzag encoded varint.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::zigzag();
+  /// assert_eq!(parser.parse(&[0x03][..]), (Output(-2), &b""[..]));
+  /// assert_eq!(parser.parse(&[0x04][..]), (Output(2), &b""[..]));
+  /// ```
+  #[inline]
+  pub const fn zigzag() -> Self { Self::new(ZigZag,) }
+}
+
+impl Parser<BeVarInt,> {
+  /// Constructs a new parser which accepts a big endian varint as used by SQLite.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::be_varint();
+  /// assert_eq!(parser.parse(&[0x81, 0x00][..]), (Output(128), &b""[..]));
+  /// assert_eq!(parser.parse(&[0x81][..]), (Pending(1), &[0x81][..]));
+  /// ```
+  #[inline]
+  pub const fn be_varint() -> Self { Self::new(BeVarInt,) }
+}
+
+impl Parser<OffsetVarInt,> {
+  /// Constructs a new parser which accepts a big endian varint as used by Git for pack
+  /// offsets.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::offset_varint();
+  /// assert_eq!(parser.parse(&[0x80, 0x00][..]), (Output(128), &b""[..]));
+  /// assert_eq!(parser.parse(&[0x7f, 0x01][..]), (Output(127), &[0x01][..]));
+  /// assert_eq!(parser.parse(&[0x81][..]), (Pending(1), &[0x81][..]));
+  /// let overflow = [0xff; 11];
+  /// assert_eq!(parser.parse(&overflow[..]), (Failed(&overflow[..10]), &overflow[..]));
+  /// ```
+  #[inline]
+  pub const fn offset_varint() -> Self { Self::new(OffsetVarInt,) }
+}
+
+impl Parser<TakeBits,> {
+  /// Constructs a new parser which accepts the next `count` bits.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::take_bits(4);
+  /// assert_eq!(parser.parse(Bits::new(&[0xa5][..])), (Output(0xa), Bits { input: &[0xa5][..], offset: 4 }));
+  /// assert_eq!(parser.parse(Bits::new(&b""[..])), (Pending(1), Bits::new(&b""[..])));
+  /// let past_end = Bits { input: &b""[..], offset: 3 };
+  /// assert_eq!(parser.parse(past_end), (Pending(1), past_end));
+  /// ```
+  #[inline]
+  pub const fn take_bits(count: usize,) -> Self { Self::new(TakeBits::new(count,),) }
+}
+
+impl Parser<BoolBit,> {
+  /// Constructs a new parser which accepts the next bit as a `bool`.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::bool_bit();
+  /// assert_eq!(parser.parse(Bits::new(&[0x80][..])), (Output(true), Bits { input: &[0x80][..], offset: 1 }));
+  /// ```
+  #[inline]
+  pub const fn bool_bit() -> Self { Self::new(BoolBit,) }
+}
+
+impl Parser<TagBits,> {
+  /// Constructs a new parser which accepts a specific sequence of `count` bits.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::tag_bits(3, 0b101);
+  /// assert_eq!(parser.parse(Bits::new(&[0xa0][..])), (Output(0b101), Bits { input: &[0xa0][..], offset: 3 }));
+  /// assert_eq!(parser.parse(Bits::new(&[0x60][..])), (Failed(0b011), Bits::new(&[0x60][..])));
+  /// ```
+  #[inline]
+  pub const fn tag_bits(count: usize, tag: u64,) -> Self { Self::new(TagBits::new(count, tag,),) }
+}
+
+impl<P,> Parser<LengthData<P,>,> {
+  /// Constructs a new parser which reads a length using `length` and then accepts that
+  /// many tokens.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::length_data(Parser::uleb128());
+  /// assert_eq!(parser.parse(&[0x02, 0xaa, 0xbb, 0xcc][..]), (Output(&[0xaa, 0xbb][..]), &[0xcc][..]));
+  /// assert_eq!(parser.parse(&[0x03, 0xaa][..]), (Pending(2), &[0x03, 0xaa][..]));
+  /// ```
+  #[inline]
+  pub const fn length_data<I,>(length: P,) -> Self
+    where Self: ParserFnOnce<I,>, { Self::new(LengthData::new(length,),) }
+}
+
+impl<P, Q,> Parser<LengthValue<P, Q,>,> {
+  /// Constructs a new parser which reads a length using `length` and then applies `value`
+  /// to exactly that many tokens.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::framing::LengthError,};
+  /// 
+  /// let parser = Parser::length_value(Parser::uleb128(), Parser::tag(&b"ab"[..]));
+  /// assert_eq!(parser.parse(&b"\x02abc"[..]), (Output(&b"ab"[..]), &b"c"[..]));
+  /// assert_eq!(parser.parse(&b"\x03abc"[..]), (Failed(LengthError::Unused(&b"c"[..])), &b"\x03abc"[..]));
+  /// ```
+  #[inline]
+  pub const fn length_value<I,>(length: P, value: Q,) -> Self
+    where Self: ParserFnOnce<I,>, { Self::new(LengthValue::new(length, value,),) }
+}
+
 impl<P,> Parser<P,> {
   /// Maps the output type of the inner parser using `map`.
   /// 
@@ -332,10 +771,14 @@ impl<P,> Parser<P,> {
   #[inline]
   pub const fn and_ok<I, Q,>(self, parser: Q,) -> Parser<AndOk<P, Q,>,>
     where P: ParserFnOnce<I,>,
-      P::Value: Try,
+      MapOk<Pair, P,>: ParserFnOnce<I,>,
       AndOk<P, Q,>: ParserFnOnce<I,>, { self.map_ok(Pair,).apply_ok(parser,) }
   /// Returns the first successful output of either parser.
   /// 
+  /// If the first parser does not succeed the second parser is applied to the input of
+  /// the first, so a sequence which fails part way through is backtracked along with any
+  /// state it updated.
+  /// 
   /// ```
   /// use ::parser::*;
   /// 
@@ -343,12 +786,463 @@ impl<P,> Parser<P,> {
   /// assert_eq!(parser.parse("abc"), (Ok('a') as Result<char, ((), ())>, "abc"));
   /// let parser = Parser::always(Err(()) as Result<char, _>).or::<&str, _>(Parser::always(Ok('b')));
   /// assert_eq!(parser.parse("abc"), (Ok('b') as Result<char, ((), ())>, "abc"));
+  /// 
+  /// let count = |&count: &u32| Output(((), count + 1)) as PResult<_, &str>;
+  /// let second = |((), b): ((), &'static str)| b;
+  /// let parser = Parser::update_state::<&str, _>(&count)
+  ///   .and_ok::<Stateful<&str, u32>, _>(Parser::tag_no_case::<&str>("b").in_state::<&str, u32>())
+  ///   .map_ok::<Stateful<&str, u32>, _>(&second)
+  ///   .or::<Stateful<&str, u32>, _>(Parser::tag_no_case::<&str>("a").in_state::<&str, u32>());
+  /// assert_eq!(parser.parse(Stateful::new("abc", 0)), (Output("a"), Stateful::new("bc", 0)));
   /// ```
   #[inline]
   pub const fn or<I, Q,>(self, parser: Q,) -> Parser<Or<P, Q,>,>
     where P: ParserFnOnce<I,>,
-      P::Value: Try,
-      Or<P, Q,>: ParserFnOnce<I,>, { self.map_err(Pair,).apply_err(parser,) }
+      MapErr<Pair, P,>: ParserFnOnce<I,>,
+      Backtrack<MapErr<Pair, P,>,>: ParserFnOnce<I,>,
+      Or<P, Q,>: ParserFnOnce<I,>, { Parser::new(Backtrack(self.map_err(Pair,).into_inner(),),).apply_err(parser,) }
+}
+
+impl<P,> Parser<P,> {
+  /// Applies this bit level parser to a byte input.
+  /// 
+  /// Any bits left in a partially consumed byte are skipped.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::take_bits(4).and::<Bits<&[u8]>, _>(Parser::bool_bit()).bits();
+  /// assert_eq!(parser.parse(&[0xa8, 0x01][..]), ((Output(0xa), Output(true)), &[0x01][..]));
+  /// ```
+  #[inline]
+  pub const fn bits<'a,>(self,) -> Parser<InBits<P,>,>
+    where InBits<P,>: ParserFnOnce<&'a [u8],>, { Parser::new(InBits::new(self.into_inner(),),) }
+  /// Applies this byte level parser to a bit input.
+  /// 
+  /// Any bits left in a partially consumed byte are skipped.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::bool_bit().and::<Bits<&[u8]>, _>(Parser::next(1).bytes());
+  /// assert_eq!(parser.parse(Bits::new(&[0x80, 0x01][..])), ((Output(true), Output(&[0x01][..])), Bits::new(&b""[..])));
+  /// let input = Bits { input: &[0x80][..], offset: 1 };
+  /// assert_eq!(Parser::next(1).bytes().parse(input), (Pending(1), input));
+  /// ```
+  #[inline]
+  pub const fn bytes<'a,>(self,) -> Parser<InBytes<P,>,>
+    where InBytes<P,>: ParserFnOnce<Bits<&'a [u8],>,>, { Parser::new(InBytes::new(self.into_inner(),),) }
+}
+
+impl<F,> Parser<GetState<F,>,> {
+  /// Constructs a new parser which outputs a value read from the user state.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let double = |depth: &u32| *depth * 2;
+  /// let parser = Parser::get_state::<&str, _>(&double);
+  /// assert_eq!(parser.parse(Stateful::new("abc", 2)), (Output(4), Stateful::new("abc", 2)));
+  /// ```
+  #[inline]
+  pub const fn get_state<I, S,>(get: F,) -> Self
+    where Self: ParserFnOnce<Stateful<I, S,>,>, { Self::new(GetState::new(get,),) }
+}
+
+impl<F,> Parser<UpdateState<F,>,> {
+  /// Constructs a new parser which replaces the user state.
+  /// 
+  /// If the update does not produce an output the state is left unchanged.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let descend = |&depth: &u32| if depth < 2 { Output((depth + 1, depth + 1)) } else { Failed("too deep") };
+  /// let parser = Parser::update_state::<&str, _>(&descend);
+  /// assert_eq!(parser.parse(Stateful::new("abc", 1)), (Output(2), Stateful::new("abc", 2)));
+  /// assert_eq!(parser.parse(Stateful::new("abc", 2)), (Failed("too deep"), Stateful::new("abc", 2)));
+  /// ```
+  #[inline]
+  pub const fn update_state<I, S,>(update: F,) -> Self
+    where Self: ParserFnOnce<Stateful<I, S,>,>, { Self::new(UpdateState::new(update,),) }
+}
+
+impl<P,> Parser<P,> {
+  /// Applies this stateful parser to an input, starting from `state`.
+  /// 
+  /// The final state is output alongside the value of the parser.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let count = |&count: &u32| Output(((), count + 1)) as PResult<_, ()>;
+  /// let parser = Parser::update_state::<&str, _>(&count).with_state::<&str, _>(0);
+  /// assert_eq!(parser.parse("abc"), ((Output(()), 1), "abc"));
+  /// ```
+  #[inline]
+  pub const fn with_state<I, S,>(self, state: S,) -> Parser<WithState<P, S,>,>
+    where WithState<P, S,>: ParserFnOnce<I,>, { Parser::new(WithState::new(self.into_inner(), state,),) }
+  /// Applies this stateless parser to a stateful input, leaving the state unchanged.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::tag("ab").in_state::<&[u8], u32>();
+  /// assert_eq!(parser.parse(Stateful::new(&b"abc"[..], 1)), (Output(&b"ab"[..]), Stateful::new(&b"c"[..], 1)));
+  /// ```
+  #[inline]
+  pub const fn in_state<I, S,>(self,) -> Parser<InState<P,>,>
+    where InState<P,>: ParserFnOnce<Stateful<I, S,>,>, { Parser::new(InState::new(self.into_inner(),),) }
+}
+
+impl Parser<SameIndent,> {
+  /// Constructs a new parser which accepts the indentation of a line at the same column as
+  /// its block.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::indent::*,};
+  /// 
+  /// let block = Indent { policy: IndentPolicy::Spaces, level: 2, };
+  /// let parser = Parser::same_indent();
+  /// assert_eq!(parser.parse(Stateful::new("\n  a", block)), (Output(2), Stateful::new("a", block)));
+  /// assert_eq!(parser.parse(Stateful::new("    a", block)), (Failed(IndentError::NotSame(4)), Stateful::new("    a", block)));
+  /// ```
+  #[inline]
+  pub const fn same_indent() -> Self { Self::new(SameIndent,) }
+}
+
+impl Parser<DeeperIndent,> {
+  /// Constructs a new parser which accepts the indentation of a line deeper than its
+  /// block.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::indent::*,};
+  /// 
+  /// let block = Indent::new(IndentPolicy::tab_width(4));
+  /// let parser = Parser::deeper_indent();
+  /// assert_eq!(parser.parse(Stateful::new(" \ta", block)), (Output(4), Stateful::new("a", block)));
+  /// assert_eq!(parser.parse(Stateful::new("a", block)), (Failed(IndentError::NotDeeper(0)), Stateful::new("a", block)));
+  /// ```
+  #[inline]
+  pub const fn deeper_indent() -> Self { Self::new(DeeperIndent,) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<P,> Parser<P,> {
+  /// Accepts a block of items indented deeper than the enclosing block using this parser
+  /// for the items.
+  /// 
+  /// Each item must consume its line ending.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::{indent::*, sources::TerminatedLine,},};
+  /// 
+  /// let top = Indent::new(IndentPolicy::Spaces);
+  /// let parser = Parser::new(TerminatedLine::new()).in_state::<&str, Indent>().indented_block();
+  /// assert_eq!(parser.parse(Stateful::new("  a\n\n  b\nc", top)), (Output(vec!["a", "b"]), Stateful::new("c", top)));
+  /// assert_eq!(
+  ///   parser.parse(Stateful::new("  a\n b\n", top)),
+  ///   (Failed(BlockError::Indent(IndentError::Unaligned(1))), Stateful::new("  a\n b\n", top)),
+  /// );
+  /// ```
+  #[inline]
+  pub const fn indented_block<'a,>(self,) -> Parser<IndentedBlock<P,>,>
+    where IndentedBlock<P,>: ParserFnOnce<Stateful<&'a str, Indent,>,>, {
+    Parser::new(IndentedBlock::new(self.into_inner(),),)
+  }
+}
+
+impl<K,> Parser<OfKind<K,>,> {
+  /// Constructs a new parser which accepts the next spanned token if it is of `kind`.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::lexer::*,};
+  /// 
+  /// #[derive(PartialEq, Debug)]
+  /// enum Token { Num(u32), Plus, }
+  /// #[derive(PartialEq, Debug)]
+  /// enum Kind { Num, Plus, }
+  /// 
+  /// impl TokenKind for Token {
+  ///   type Kind = Kind;
+  /// 
+  ///   fn kind(&self) -> Kind {
+  ///     match self { Token::Num(_) => Kind::Num, Token::Plus => Kind::Plus, }
+  ///   }
+  /// }
+  /// 
+  /// let tokens = [Spanned::new(Token::Num(1), Span::new(0, 1)), Spanned::new(Token::Plus, Span::new(2, 3))];
+  /// let parser = Parser::token_kind::<&[Spanned<Token>]>(Kind::Num);
+  /// assert_eq!(parser.parse(&tokens[..]), (Output(&tokens[0]), &tokens[1..]));
+  /// assert_eq!(parser.parse(&tokens[1..]).value.failed().map(|token| token.span), Some(Span::new(2, 3)));
+  /// ```
+  #[inline]
+  pub const fn token_kind<I,>(kind: K,) -> Self
+    where Self: ParserFnOnce<I,>, { Self::new(OfKind::new(kind,),) }
+}
+
+impl<P,> Parser<P,> {
+  /// Returns an iterator which splits `input` into spanned tokens using this parser.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::lexer::*,};
+  /// 
+  /// let mut tokens = Parser::tags::<_, _, &str>([("let", 0), ("=", 1), ("x", 2)]).lex("let x = x;");
+  /// assert_eq!(tokens.next(), Some(Output(Spanned::new(("let", 0), Span::new(0, 3)))));
+  /// assert_eq!(tokens.nth(2), Some(Output(Spanned::new(("x", 2), Span::new(8, 9)))));
+  /// assert_eq!(tokens.next(), Some(Failed(Spanned::new("", Span::new(9, 9)))));
+  /// assert_eq!(tokens.next(), None);
+  /// let mut tokens = Parser::tags::<_, _, &str>([("let", 0), ("x", 2)]).lex("let,x").trivia(Parser::tag_no_case::<&str>(","));
+  /// assert_eq!(tokens.nth(1), Some(Output(Spanned::new(("x", 2), Span::new(4, 5)))));
+  /// ```
+  #[inline]
+  pub const fn lex<'a,>(self, input: &'a str,) -> Lexer<'a, P,>
+    where Lexer<'a, P,>: Iterator, { Lexer::new(self.into_inner(), input,) }
+}
+
+impl<P,> Parser<P,> {
+  /// Returns an iterator which applies this parser to each complete line of `input`.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let mut lines = Parser::next(1).lines(&b"ab\ncd\r\nef"[..]);
+  /// assert_eq!(lines.next(), Some(Output(&b"a"[..])));
+  /// assert_eq!(lines.next(), Some(Output(&b"c"[..])));
+  /// assert_eq!(lines.next(), None);
+  /// assert_eq!(lines.into_unused(), &b"ef"[..]);
+  /// ```
+  #[inline]
+  pub const fn lines<I,>(self, input: I,) -> Lines<P, I,>
+    where Lines<P, I,>: Iterator, { Lines::new(self.into_inner(), input,) }
+}
+
+impl<P,> Parser<P,> {
+  /// Returns an iterator which applies this parser to `input` and then to the input
+  /// unused by each output.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::iter::*,};
+  /// 
+  /// let mut records = Parser::next(2).iter(&b"abcde"[..]);
+  /// assert_eq!(records.next(), Some(&b"ab"[..]));
+  /// assert_eq!(records.next(), Some(&b"cd"[..]));
+  /// assert_eq!(records.next(), None);
+  /// assert_eq!(records.into_parts(), (&b"e"[..], Some(Stop::Pending(1))));
+  /// ```
+  #[inline]
+  pub const fn iter<I, E,>(self, input: I,) -> ParseIter<P, I, E,>
+    where ParseIter<P, I, E,>: Iterator, { ParseIter::new(self.into_inner(), input,) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<P,> Parser<P,> {
+  /// Applies this parser to its own unused input until it fails and outputs each output
+  /// in order.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::tag("ab").many::<&[u8]>();
+  /// assert_eq!(parser.parse(&b"ababc"[..]), (Output(vec![&b"ab"[..], &b"ab"[..]]), &b"c"[..]));
+  /// assert_eq!(parser.parse(&b"c"[..]), (Output(vec![]), &b"c"[..]));
+  /// assert_eq!(parser.parse(&b"aba"[..]), (Pending(1), &b"aba"[..]));
+  /// ```
+  #[inline]
+  pub const fn many<I,>(self,) -> Parser<Many<P,>,>
+    where Many<P,>: ParserFnOnce<I,>, { Parser::new(Many::new(self.into_inner(), 0,),) }
+  /// Applies this parser to its own unused input until it fails and outputs each output
+  /// in order, failing if there are no outputs.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::tag("ab").many1::<&[u8]>();
+  /// assert_eq!(parser.parse(&b"abc"[..]), (Output(vec![&b"ab"[..]]), &b"c"[..]));
+  /// assert_eq!(parser.parse(&b"c"[..]), (Failed(&b""[..]), &b"c"[..]));
+  /// ```
+  #[inline]
+  pub const fn many1<I,>(self,) -> Parser<Many<P,>,>
+    where Many<P,>: ParserFnOnce<I,>, { Parser::new(Many::new(self.into_inner(), 1,),) }
+}
+
+impl<P,> Parser<P,> {
+  /// Outputs `None` instead of the failure of this parser.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::tag("ab").opt::<&[u8]>();
+  /// assert_eq!(parser.parse(&b"abc"[..]), (Output(Some(&b"ab"[..])), &b"c"[..]));
+  /// assert_eq!(parser.parse(&b"c"[..]), (Output(None), &b"c"[..]));
+  /// ```
+  #[inline]
+  pub const fn opt<I,>(self,) -> Parser<Opt<P,>,>
+    where Opt<P,>: ParserFnOnce<I,>, { Parser::new(Opt::new(self.into_inner(),),) }
+}
+
+impl<P,> Parser<P,> {
+  /// Skips the trivia following the output of this parser.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::sources::Trivia,};
+  /// 
+  /// const TRIVIA: Trivia = Trivia::WHITESPACE.line_comment("//").block_comment("/*", "*/").nested();
+  /// let parser = Parser::tag("let").lexeme::<&[u8], _>(TRIVIA);
+  /// assert_eq!(parser.parse(&b"let /* a /* b */ */ x"[..]), (Output(&b"let"[..]), &b"x"[..]));
+  /// assert_eq!(parser.parse(&b"let // x"[..]), (Pending(1), &b"let // x"[..]));
+  /// ```
+  #[inline]
+  pub const fn lexeme<I, T,>(self, trivia: T,) -> Parser<Lexeme<P, T,>,>
+    where Lexeme<P, T,>: ParserFnOnce<I,>, { Parser::new(Lexeme::new(self.into_inner(), trivia,),) }
+  /// Skips the trivia preceding this parser.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::sources::Trivia,};
+  /// 
+  /// let parser = Parser::tags::<_, _, &str>([("if", 0), ("else", 1)]).token::<&str, _>(Trivia::WHITESPACE);
+  /// assert_eq!(parser.parse("  if x"), (Output(("if", 0)), " x"));
+  /// assert_eq!(parser.parse("  for"), (Failed(""), "  for"));
+  /// ```
+  #[inline]
+  pub const fn token<I, T,>(self, trivia: T,) -> Parser<Token<P, T,>,>
+    where Token<P, T,>: ParserFnOnce<I,>, { Parser::new(Token::new(self.into_inner(), trivia,),) }
+  /// Skips the trivia of a [`Skipping`](crate::parser::lexeme::Skipping) input following
+  /// the output of this parser.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::{sources::Trivia, lexeme::Skipping,},};
+  /// 
+  /// let parser = Parser::tag("let").skip_trivia::<Skipping<&[u8], Trivia>>();
+  /// assert_eq!(parser.parse(Skipping::new(&b"let x"[..], Trivia::WHITESPACE)), (Output(&b"let"[..]), Skipping::new(&b"x"[..], Trivia::WHITESPACE)));
+  /// ```
+  #[inline]
+  pub const fn skip_trivia<I,>(self,) -> Parser<SkipTrivia<P,>,>
+    where SkipTrivia<P,>: ParserFnOnce<I,>, { Parser::new(SkipTrivia::new(self.into_inner(),),) }
+  /// Applies this parser to a [`Skipping`](crate::parser::lexeme::Skipping) input carrying
+  /// `trivia`, skipping the leading trivia first.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::{sources::Trivia, lexeme::Skipping,},};
+  /// 
+  /// const TRIVIA: Trivia = Trivia::WHITESPACE.line_comment("//");
+  /// let parser = Parser::tag("let").skip_trivia::<Skipping<&[u8], Trivia>>()
+  ///   .and_ok::<Skipping<&[u8], Trivia>, _>(Parser::tag("x").skip_trivia::<Skipping<&[u8], Trivia>>())
+  ///   .with_trivia::<&[u8], _>(TRIVIA);
+  /// assert_eq!(parser.parse(&b" let // x\n x;"[..]), (Output((&b"let"[..], &b"x"[..])), &b";"[..]));
+  /// assert_eq!(parser.parse(&b" let y;"[..]), (Failed(&b""[..]), &b" let y;"[..]));
+  /// ```
+  #[inline]
+  pub const fn with_trivia<I, T,>(self, trivia: T,) -> Parser<WithTrivia<P, T,>,>
+    where WithTrivia<P, T,>: ParserFnOnce<I,>, { Parser::new(WithTrivia::new(self.into_inner(), trivia,),) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<P,> Parser<P,> {
+  /// Caches the parses of this parser in `table` as a new rule.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::memo::MemoTable,};
+  /// 
+  /// let table = MemoTable::new();
+  /// let parser = Parser::tag("ab").memo::<&[u8]>(&table);
+  /// assert_eq!(parser.parse(&b"abc"[..]), (Output(&b"ab"[..]), &b"c"[..]));
+  /// assert_eq!(parser.parse(&b"abc"[..]), (Output(&b"ab"[..]), &b"c"[..]));
+  /// assert_eq!(table.stats().hits, 1);
+  /// assert_eq!(table.stats().hit_rate(), 0.5);
+  /// table.clear();
+  /// assert_eq!(table.stats().entries, 0);
+  /// ```
+  #[inline]
+  pub fn memo<'t, I,>(self, table: &'t MemoTable<P::Value, I,>,) -> Parser<Memo<'t, P, P::Value, I,>,>
+    where P: ParserFn<I,>,
+      Memo<'t, P, P::Value, I,>: ParserFn<I,>, { Parser::new(Memo::new(self.into_inner(), table,),) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<F, T, E, I,> Parser<LeftRec<F, PResult<T, E,>, I,>,>
+  where F: Fn(Recur<'_, T, E, I,>, I,) -> Parse<PResult<T, E,>, I,>,
+    T: Clone,
+    I: Remaining + Clone, {
+  /// Constructs a new parser for a rule which may be directly left recursive.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// //expr := expr '-' digit | digit
+  /// let value = |&[d]: &[u8; 1]| (d - b'0') as i32;
+  /// let digit = Parser::one_of("0123456789").map_ok(&value);
+  /// let parser = Parser::left_rec(|expr, input| {
+  ///   if let Parse { value: Output(lhs), unused } = expr(input) {
+  ///     if let Parse { value: Output(_), unused } = Parser::tag("-").parse(unused) {
+  ///       if let Parse { value: Output(rhs), unused } = digit.parse(unused) {
+  ///         return Parse::new(Output(lhs - rhs), unused)
+  ///       }
+  ///     }
+  ///   }
+  ///   digit.parse(input)
+  /// });
+  /// assert_eq!(parser.parse(&b"9-2-3;"[..]), (Output(4), &b";"[..]));
+  /// ```
+  #[inline]
+  pub fn left_rec(body: F,) -> Self { Self::new(LeftRec::new(body,),) }
+}
+
+impl<P,> Parser<P,> {
+  /// Traces the application of this parser as a rule named `name`.
+  /// 
+  /// With the `std` or `log` features the rule is reported while tracing is enabled, see
+  /// the [`trace`](crate::parser::trace) module. Otherwise this parser is returned
+  /// unchanged.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::tag("ab").trace::<&[u8]>("ab");
+  /// assert_eq!(parser.parse(&b"abc"[..]), (Output(&b"ab"[..]), &b"c"[..]));
+  /// ```
+  #[cfg(any(feature = "std", feature = "log",),)]
+  #[inline]
+  pub const fn trace<I,>(self, name: &'static str,) -> Parser<Trace<P,>,>
+    where Trace<P,>: ParserFnOnce<I,>, { Parser::new(Trace::new(name, self.into_inner(),),) }
+  /// Traces the application of this parser as a rule named `name`.
+  /// 
+  /// With the `std` or `log` features the rule is reported while tracing is enabled, see
+  /// the `trace` module. Otherwise this parser is returned unchanged.
+  /// 
+  /// ```
+  /// use ::parser::*;
+  /// 
+  /// let parser = Parser::tag("ab").trace::<&[u8]>("ab");
+  /// assert_eq!(parser.parse(&b"abc"[..]), (Output(&b"ab"[..]), &b"c"[..]));
+  /// ```
+  #[cfg(not(any(feature = "std", feature = "log",),),)]
+  #[inline]
+  pub const fn trace<I,>(self, _name: &'static str,) -> Self
+    where P: ParserFnOnce<I,>,
+      P::Value: Variant,
+      I: Remaining, { self }
+  /// Reports the application of this parser as a rule named `name` to `observer`.
+  /// 
+  /// ```
+  /// use ::parser::{*, parser::observe::*,};
+  /// use core::cell::Cell;
+  /// 
+  /// struct Backtracks(Cell<usize>);
+  /// 
+  /// impl ParseObserver for Backtracks {
+  ///   fn backtrack(&self, _rule: &'static str) { self.0.set(self.0.get() + 1) }
+  /// }
+  /// 
+  /// let backtracks = Backtracks(Cell::new(0));
+  /// let parser = Parser::tag("ab").observe::<&[u8],_>("ab", &backtracks);
+  /// assert_eq!(parser.parse(&b"abc"[..]), (Output(&b"ab"[..]), &b"c"[..]));
+  /// assert_eq!(parser.parse(&b"ba"[..]), (Failed(&b""[..]), &b"ba"[..]));
+  /// assert_eq!(backtracks.0.get(), 1);
+  /// ```
+  #[inline]
+  pub const fn observe<I, O,>(self, name: &'static str, observer: O,) -> Parser<Observe<P, O,>,>
+    where Observe<P, O,>: ParserFnOnce<I,>, { Parser::new(Observe::new(name, self.into_inner(), observer,),) }
 }
 
 impl<P,> From<P> for Parser<P,> {
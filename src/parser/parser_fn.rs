@@ -1,7 +1,8 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
 use crate::*;
+use super::lexeme::Skipping;
 
 /// A trait for once-off parsers.
 /// 
@@ -49,3 +50,73 @@ pub trait ParserFn<Input,>: ParserFnMut<Input,> + Fn(Input,) -> Parse<Self::Valu
 
 impl<F, I, O,> ParserFn<I,> for F
   where F: Fn(I,) -> Parse<O, I,>, {}
+
+/// An input whose positions can be identified by the number of tokens remaining.
+/// 
+/// Within a single input the remaining length uniquely identifies an offset.
+pub trait Remaining {
+  /// The number of tokens remaining in the input.
+  fn remaining(&self,) -> usize;
+}
+
+impl<T,> Remaining for &'_ [T] {
+  #[inline]
+  fn remaining(&self,) -> usize { self.len() }
+}
+
+impl Remaining for &'_ str {
+  #[inline]
+  fn remaining(&self,) -> usize { self.len() }
+}
+
+impl Remaining for Bits<&'_ [u8],> {
+  #[inline]
+  fn remaining(&self,) -> usize { self.len() }
+}
+
+impl<I: Remaining, S,> Remaining for Stateful<I, S,> {
+  #[inline]
+  fn remaining(&self,) -> usize { self.input.remaining() }
+}
+
+impl<I: Remaining, T,> Remaining for Skipping<I, T,> {
+  #[inline]
+  fn remaining(&self,) -> usize { self.input.remaining() }
+}
+
+/// A value whose variant can be traced.
+pub trait Variant {
+  /// The name of the variant of this value.
+  fn variant(&self,) -> &'static str;
+}
+
+impl<T, E,> Variant for PResult<T, E,> {
+  #[inline]
+  fn variant(&self,) -> &'static str {
+    match self {
+      Output(_,) => "Output",
+      Pending(_,) => "Pending",
+      Failed(_,) => "Failed",
+    }
+  }
+}
+
+impl<T, E,> Variant for Result<T, E,> {
+  #[inline]
+  fn variant(&self,) -> &'static str {
+    match self {
+      Ok(_,) => "Ok",
+      Err(_,) => "Err",
+    }
+  }
+}
+
+impl<T,> Variant for Option<T,> {
+  #[inline]
+  fn variant(&self,) -> &'static str {
+    match self {
+      Some(_,) => "Some",
+      None => "None",
+    }
+  }
+}
@@ -0,0 +1,398 @@
+//! A precedence climbing parser for expression grammars.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+
+/// The associativity of an infix operator.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum Assoc {
+  /// `a - b - c` is folded as `(a - b) - c`.
+  Left,
+  /// `a ^ b ^ c` is folded as `a ^ (b ^ c)`.
+  Right,
+}
+
+/// A prefix operator.
+#[derive(Clone, Copy, Debug,)]
+pub struct Prefix<P, F,> {
+  /// The parser for the operator.
+  pub parser: P,
+  /// The binding power of the operator, higher powers bind tighter.
+  pub power: u8,
+  /// Folds the operand into an expression.
+  pub fold: F,
+}
+
+impl<P, F,> Prefix<P, F,> {
+  /// Constructs a new `Prefix` operator.
+  #[inline]
+  pub const fn new<T,>(parser: P, power: u8, fold: F,) -> Self
+    where F: Fn(T,) -> T, { Prefix { parser, power, fold, } }
+}
+
+/// An infix operator.
+#[derive(Clone, Copy, Debug,)]
+pub struct Infix<P, F,> {
+  /// The parser for the operator.
+  pub parser: P,
+  /// The binding power of the operator, higher powers bind tighter.
+  pub power: u8,
+  /// The associativity of the operator.
+  pub assoc: Assoc,
+  /// Folds the operands into an expression.
+  pub fold: F,
+}
+
+impl<P, F,> Infix<P, F,> {
+  /// Constructs a new `Infix` operator.
+  #[inline]
+  pub const fn new<T,>(parser: P, power: u8, assoc: Assoc, fold: F,) -> Self
+    where F: Fn(T, T,) -> T, { Infix { parser, power, assoc, fold, } }
+}
+
+/// A postfix operator.
+#[derive(Clone, Copy, Debug,)]
+pub struct Postfix<P, F,> {
+  /// The parser for the operator.
+  pub parser: P,
+  /// The binding power of the operator, higher powers bind tighter.
+  pub power: u8,
+  /// Folds the operand into an expression.
+  pub fold: F,
+}
+
+impl<P, F,> Postfix<P, F,> {
+  /// Constructs a new `Postfix` operator.
+  #[inline]
+  pub const fn new<T,>(parser: P, power: u8, fold: F,) -> Self
+    where F: Fn(T,) -> T, { Postfix { parser, power, fold, } }
+}
+
+/// A table of prefix operators.
+/// 
+/// Single operators, slices of operators and tuples of tables are tables, their
+/// operators are tried in order.
+pub trait PrefixTable<I, T,> {
+  /// Applies the first operator which accepts the front of `input`.
+  /// 
+  /// `operand` parses an expression whose operators bind at least as tightly as the power
+  /// it is given. If no operator accepts the input `None` is returned.
+  fn apply_prefix<E, G,>(&self, input: I, operand: G,) -> Option<Parse<PResult<T, E,>, I,>>
+    where G: FnMut(I, u32,) -> Parse<PResult<T, E,>, I,>;
+}
+
+/// A table of infix operators.
+/// 
+/// Single operators, slices of operators and tuples of tables are tables, their
+/// operators are tried in order.
+pub trait InfixTable<I, T,> {
+  /// Applies the first operator which binds at least as tightly as `min` and accepts the
+  /// front of `input` to `lhs`.
+  /// 
+  /// `operand` parses an expression whose operators bind at least as tightly as the power
+  /// it is given. If no operator accepts the input `lhs` is returned as the error.
+  fn apply_infix<E, G,>(&self, lhs: T, input: I, min: u32, operand: G,) -> Result<Parse<PResult<T, E,>, I,>, T>
+    where G: FnMut(I, u32,) -> Parse<PResult<T, E,>, I,>;
+}
+
+/// A table of postfix operators.
+/// 
+/// Single operators, slices of operators and tuples of tables are tables, their
+/// operators are tried in order.
+pub trait PostfixTable<I, T,> {
+  /// Applies the first operator which binds at least as tightly as `min` and accepts the
+  /// front of `input` to `lhs`.
+  /// 
+  /// If no operator accepts the input `lhs` is returned as the error.
+  fn apply_postfix<E,>(&self, lhs: T, input: I, min: u32,) -> Result<Parse<PResult<T, E,>, I,>, T>;
+}
+
+impl<P, F, T, O, X, I,> PrefixTable<I, T,> for Prefix<P, F,>
+  where P: ParserFn<I, Value = PResult<O, X,>,>,
+    F: Fn(T,) -> T,
+    I: Clone, {
+  fn apply_prefix<E, G,>(&self, input: I, mut operand: G,) -> Option<Parse<PResult<T, E,>, I,>>
+    where G: FnMut(I, u32,) -> Parse<PResult<T, E,>, I,>, {
+    match self.parser.parse(input.clone(),) {
+      Parse { value: Output(_,), unused, } => Some(match operand(unused, 2 * self.power as u32 + 1,) {
+        Parse { value: Output(rhs,), unused, } => Parse::new(Output((self.fold)(rhs,),), unused,),
+        Parse { value, .. } => Parse::new(value, input,),
+      },),
+      Parse { value: Pending(pending,), .. } => Some(Parse::new(Pending(pending,), input,),),
+      Parse { value: Failed(_,), .. } => None,
+    }
+  }
+}
+
+impl<P, F, T, O, X, I,> InfixTable<I, T,> for Infix<P, F,>
+  where P: ParserFn<I, Value = PResult<O, X,>,>,
+    F: Fn(T, T,) -> T,
+    I: Clone, {
+  fn apply_infix<E, G,>(&self, lhs: T, input: I, min: u32, mut operand: G,) -> Result<Parse<PResult<T, E,>, I,>, T>
+    where G: FnMut(I, u32,) -> Parse<PResult<T, E,>, I,>, {
+    let (left, right,) = match self.assoc {
+      Assoc::Left => (2 * self.power as u32, 2 * self.power as u32 + 1,),
+      Assoc::Right => (2 * self.power as u32 + 1, 2 * self.power as u32,),
+    };
+    if left < min { return Err(lhs,) }
+
+    match self.parser.parse(input.clone(),) {
+      Parse { value: Output(_,), unused, } => Ok(match operand(unused, right,) {
+        Parse { value: Output(rhs,), unused, } => Parse::new(Output((self.fold)(lhs, rhs,),), unused,),
+        Parse { value, .. } => Parse::new(value, input,),
+      },),
+      Parse { value: Pending(pending,), .. } => Ok(Parse::new(Pending(pending,), input,),),
+      Parse { value: Failed(_,), .. } => Err(lhs,),
+    }
+  }
+}
+
+impl<P, F, T, O, X, I,> PostfixTable<I, T,> for Postfix<P, F,>
+  where P: ParserFn<I, Value = PResult<O, X,>,>,
+    F: Fn(T,) -> T,
+    I: Clone, {
+  fn apply_postfix<E,>(&self, lhs: T, input: I, min: u32,) -> Result<Parse<PResult<T, E,>, I,>, T> {
+    if 2 * (self.power as u32) < min { return Err(lhs,) }
+
+    match self.parser.parse(input.clone(),) {
+      Parse { value: Output(_,), unused, } => Ok(Parse::new(Output((self.fold)(lhs,),), unused,),),
+      Parse { value: Pending(pending,), .. } => Ok(Parse::new(Pending(pending,), input,),),
+      Parse { value: Failed(_,), .. } => Err(lhs,),
+    }
+  }
+}
+
+impl<Op, T, I,> PrefixTable<I, T,> for [Op]
+  where Op: PrefixTable<I, T,>,
+    I: Clone, {
+  fn apply_prefix<E, G,>(&self, input: I, mut operand: G,) -> Option<Parse<PResult<T, E,>, I,>>
+    where G: FnMut(I, u32,) -> Parse<PResult<T, E,>, I,>, {
+    self.iter().find_map(|op,| op.apply_prefix(input.clone(), &mut operand,),)
+  }
+}
+
+impl<Op, T, I,> InfixTable<I, T,> for [Op]
+  where Op: InfixTable<I, T,>,
+    I: Clone, {
+  fn apply_infix<E, G,>(&self, mut lhs: T, input: I, min: u32, mut operand: G,) -> Result<Parse<PResult<T, E,>, I,>, T>
+    where G: FnMut(I, u32,) -> Parse<PResult<T, E,>, I,>, {
+    for op in self {
+      lhs = match op.apply_infix(lhs, input.clone(), min, &mut operand,) {
+        Ok(parse,) => return Ok(parse,),
+        Err(lhs,) => lhs,
+      };
+    }
+
+    Err(lhs,)
+  }
+}
+
+impl<Op, T, I,> PostfixTable<I, T,> for [Op]
+  where Op: PostfixTable<I, T,>,
+    I: Clone, {
+  fn apply_postfix<E,>(&self, mut lhs: T, input: I, min: u32,) -> Result<Parse<PResult<T, E,>, I,>, T> {
+    for op in self {
+      lhs = match op.apply_postfix(lhs, input.clone(), min,) {
+        Ok(parse,) => return Ok(parse,),
+        Err(lhs,) => lhs,
+      };
+    }
+
+    Err(lhs,)
+  }
+}
+
+impl<Tb, T, I,> PrefixTable<I, T,> for &'_ Tb
+  where Tb: PrefixTable<I, T,> + ?Sized, {
+  #[inline]
+  fn apply_prefix<E, G,>(&self, input: I, operand: G,) -> Option<Parse<PResult<T, E,>, I,>>
+    where G: FnMut(I, u32,) -> Parse<PResult<T, E,>, I,>, { (**self).apply_prefix(input, operand,) }
+}
+
+impl<Tb, T, I,> InfixTable<I, T,> for &'_ Tb
+  where Tb: InfixTable<I, T,> + ?Sized, {
+  #[inline]
+  fn apply_infix<E, G,>(&self, lhs: T, input: I, min: u32, operand: G,) -> Result<Parse<PResult<T, E,>, I,>, T>
+    where G: FnMut(I, u32,) -> Parse<PResult<T, E,>, I,>, { (**self).apply_infix(lhs, input, min, operand,) }
+}
+
+impl<Tb, T, I,> PostfixTable<I, T,> for &'_ Tb
+  where Tb: PostfixTable<I, T,> + ?Sized, {
+  #[inline]
+  fn apply_postfix<E,>(&self, lhs: T, input: I, min: u32,) -> Result<Parse<PResult<T, E,>, I,>, T> {
+    (**self).apply_postfix(lhs, input, min,)
+  }
+}
+
+macro_rules! impl_tables {
+  ($($name:ident,)*) => {
+    impl<T, I, $($name,)*> PrefixTable<I, T,> for ($($name,)*)
+      where I: Clone, $($name: PrefixTable<I, T,>,)* {
+      #[allow(non_snake_case, unused_variables, unused_mut,)]
+      fn apply_prefix<E, G,>(&self, input: I, mut operand: G,) -> Option<Parse<PResult<T, E,>, I,>>
+        where G: FnMut(I, u32,) -> Parse<PResult<T, E,>, I,>, {
+        let ($($name,)*) = self;
+        $(if let Some(parse,) = $name.apply_prefix(input.clone(), &mut operand,) { return Some(parse,) })*
+
+        None
+      }
+    }
+
+    impl<T, I, $($name,)*> InfixTable<I, T,> for ($($name,)*)
+      where I: Clone, $($name: InfixTable<I, T,>,)* {
+      #[allow(non_snake_case, unused_variables, unused_mut,)]
+      fn apply_infix<E, G,>(&self, lhs: T, input: I, min: u32, mut operand: G,) -> Result<Parse<PResult<T, E,>, I,>, T>
+        where G: FnMut(I, u32,) -> Parse<PResult<T, E,>, I,>, {
+        let ($($name,)*) = self;
+        $(let lhs = match $name.apply_infix(lhs, input.clone(), min, &mut operand,) {
+          Ok(parse,) => return Ok(parse,),
+          Err(lhs,) => lhs,
+        };)*
+
+        Err(lhs,)
+      }
+    }
+
+    impl<T, I, $($name,)*> PostfixTable<I, T,> for ($($name,)*)
+      where I: Clone, $($name: PostfixTable<I, T,>,)* {
+      #[allow(non_snake_case, unused_variables,)]
+      fn apply_postfix<E,>(&self, lhs: T, input: I, min: u32,) -> Result<Parse<PResult<T, E,>, I,>, T> {
+        let ($($name,)*) = self;
+        $(let lhs = match $name.apply_postfix(lhs, input.clone(), min,) {
+          Ok(parse,) => return Ok(parse,),
+          Err(lhs,) => lhs,
+        };)*
+
+        Err(lhs,)
+      }
+    }
+  };
+}
+
+impl_tables!();
+impl_tables!(A,);
+impl_tables!(A, B,);
+impl_tables!(A, B, C,);
+impl_tables!(A, B, C, D,);
+impl_tables!(A, B, C, D, F,);
+impl_tables!(A, B, C, D, F, H,);
+impl_tables!(A, B, C, D, F, H, J,);
+impl_tables!(A, B, C, D, F, H, J, K,);
+impl_tables!(A, B, C, D, F, H, J, K, L,);
+impl_tables!(A, B, C, D, F, H, J, K, L, M,);
+impl_tables!(A, B, C, D, F, H, J, K, L, M, N,);
+impl_tables!(A, B, C, D, F, H, J, K, L, M, N, O,);
+
+/// A parser for expressions built from atoms and tables of operators.
+/// 
+/// Operators are tried in the order of their table, the first to succeed is used. The
+/// failure of an operator parser only means that the operator is absent, if an operator
+/// parser is pending the expression is pending. If an atom or the operand of an operator
+/// fails the error is returned.
+#[derive(Clone, Copy, Debug,)]
+pub struct PrattParser<A, Pre = (), In = (), Post = (),> {
+  /// The parser for the atoms of the expression.
+  atom: A,
+  /// The prefix operators.
+  prefix: Pre,
+  /// The infix operators.
+  infix: In,
+  /// The postfix operators.
+  postfix: Post,
+}
+
+impl<A,> PrattParser<A,> {
+  /// Constructs a new `PrattParser` from the `atom` parser without any operators.
+  #[inline]
+  pub const fn new(atom: A,) -> Self { PrattParser { atom, prefix: (), infix: (), postfix: (), } }
+}
+
+impl<A, Pre, In, Post,> PrattParser<A, Pre, In, Post,> {
+  /// Sets the prefix operators.
+  #[inline]
+  pub fn prefix<Tb,>(self, prefix: Tb,) -> PrattParser<A, Tb, In, Post,> {
+    let PrattParser { atom, infix, postfix, .. } = self;
+    PrattParser { atom, prefix, infix, postfix, }
+  }
+  /// Sets the infix operators.
+  #[inline]
+  pub fn infix<Tb,>(self, infix: Tb,) -> PrattParser<A, Pre, Tb, Post,> {
+    let PrattParser { atom, prefix, postfix, .. } = self;
+    PrattParser { atom, prefix, infix, postfix, }
+  }
+  /// Sets the postfix operators.
+  #[inline]
+  pub fn postfix<Tb,>(self, postfix: Tb,) -> PrattParser<A, Pre, In, Tb,> {
+    let PrattParser { atom, prefix, infix, .. } = self;
+    PrattParser { atom, prefix, infix, postfix, }
+  }
+  /// Parses an expression whose operators bind at least as tightly as `min`.
+  fn expr<I, T, E,>(&self, input: I, min: u32,) -> Parse<PResult<T, E,>, I,>
+    where A: ParserFn<I, Value = PResult<T, E,>,>,
+      Pre: PrefixTable<I, T,>,
+      In: InfixTable<I, T,>,
+      Post: PostfixTable<I, T,>,
+      I: Clone, {
+    let operand = |input, min,| self.expr(input, min,);
+    let lhs = match self.prefix.apply_prefix(input.clone(), operand,) {
+      Some(lhs,) => lhs,
+      None => self.atom.parse(input.clone(),),
+    };
+    let (mut lhs, mut rest,) = match lhs {
+      Parse { value: Output(lhs,), unused, } => (lhs, unused,),
+      Parse { value, .. } => return Parse::new(value, input,),
+    };
+
+    loop {
+      let next = match self.postfix.apply_postfix(lhs, rest.clone(), min,) {
+        Ok(next,) => next,
+        Err(lhs,) => match self.infix.apply_infix(lhs, rest.clone(), min, operand,) {
+          Ok(next,) => next,
+          Err(lhs,) => return Parse::new(Output(lhs,), rest,),
+        },
+      };
+
+      match next {
+        Parse { value: Output(value,), unused, } => {
+          lhs = value;
+          rest = unused;
+        },
+        Parse { value, .. } => return Parse::new(value, input,),
+      }
+    }
+  }
+}
+
+impl<A, Pre, In, Post, T, E, I,> FnOnce<(I,),> for PrattParser<A, Pre, In, Post,>
+  where A: ParserFn<I, Value = PResult<T, E,>,>,
+    Pre: PrefixTable<I, T,>,
+    In: InfixTable<I, T,>,
+    Post: PostfixTable<I, T,>,
+    I: Clone, {
+  type Output = Parse<PResult<T, E,>, I,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output { self.expr(input, 0,) }
+}
+
+impl<A, Pre, In, Post, T, E, I,> FnMut<(I,),> for PrattParser<A, Pre, In, Post,>
+  where A: ParserFn<I, Value = PResult<T, E,>,>,
+    Pre: PrefixTable<I, T,>,
+    In: InfixTable<I, T,>,
+    Post: PostfixTable<I, T,>,
+    I: Clone, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output { self.expr(input, 0,) }
+}
+
+impl<A, Pre, In, Post, T, E, I,> Fn<(I,),> for PrattParser<A, Pre, In, Post,>
+  where A: ParserFn<I, Value = PResult<T, E,>,>,
+    Pre: PrefixTable<I, T,>,
+    In: InfixTable<I, T,>,
+    Post: PostfixTable<I, T,>,
+    I: Clone, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output { self.expr(input, 0,) }
+}
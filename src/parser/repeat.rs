@@ -0,0 +1,129 @@
+//! Adaptors which apply a parser repeatedly or optionally.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+#[cfg(feature = "alloc",)]
+use {crate::parser::Remaining, alloc::vec::Vec,};
+
+/// A parser which applies the inner parser to its own unused input until it fails and
+/// outputs each output in order.
+/// 
+/// If fewer than `min` outputs were produced the failure is returned. If the inner parser
+/// is pending the parse is pending. If the inner parser outputs without consuming any
+/// input repetition stops, as it would otherwise repeat forever.
+#[cfg(feature = "alloc",)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Many<P,> {
+  /// The parser to repeat.
+  pub parser: P,
+  /// The minimum number of outputs.
+  pub min: usize,
+}
+
+#[cfg(feature = "alloc",)]
+impl<P,> Many<P,> {
+  /// Constructs a new `Many` repeating `parser` at least `min` times.
+  #[inline]
+  pub const fn new(parser: P, min: usize,) -> Self { Many { parser, min, } }
+}
+
+#[cfg(feature = "alloc",)]
+impl<P, T, E, I,> FnOnce<(I,),> for Many<P,>
+  where P: ParserFnMut<I, Value = PResult<T, E,>,>,
+    I: Remaining + Clone, {
+  type Output = Parse<PResult<Vec<T>, E,>, I,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(mut self, (input,): (I,),) -> Self::Output {
+    many(self.min, input, |unused,| self.parser.parse_mut(unused,),)
+  }
+}
+
+#[cfg(feature = "alloc",)]
+impl<P, T, E, I,> FnMut<(I,),> for Many<P,>
+  where P: ParserFnMut<I, Value = PResult<T, E,>,>,
+    I: Remaining + Clone, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output {
+    let parser = &mut self.parser;
+    many(self.min, input, |unused,| parser.parse_mut(unused,),)
+  }
+}
+
+#[cfg(feature = "alloc",)]
+impl<P, T, E, I,> Fn<(I,),> for Many<P,>
+  where P: ParserFn<I, Value = PResult<T, E,>,>,
+    I: Remaining + Clone, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output {
+    many(self.min, input, |unused,| self.parser.parse(unused,),)
+  }
+}
+
+/// A parser which outputs `None` instead of the failure of the inner parser.
+/// 
+/// If the inner parser is pending the parse is pending.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Opt<P,>(pub P,);
+
+impl<P,> Opt<P,> {
+  /// Constructs a new `Opt` from `parser`.
+  #[inline]
+  pub const fn new(parser: P,) -> Self { Opt(parser,) }
+}
+
+impl<P, T, E, I,> FnOnce<(I,),> for Opt<P,>
+  where P: ParserFnOnce<I, Value = PResult<T, E,>,>, {
+  type Output = Parse<PResult<Option<T>, E,>, I,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output { opt(self.0.parse_once(input,),) }
+}
+
+impl<P, T, E, I,> FnMut<(I,),> for Opt<P,>
+  where P: ParserFnMut<I, Value = PResult<T, E,>,>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output { opt(self.0.parse_mut(input,),) }
+}
+
+impl<P, T, E, I,> Fn<(I,),> for Opt<P,>
+  where P: ParserFn<I, Value = PResult<T, E,>,>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output { opt(self.0.parse(input,),) }
+}
+
+/// Applies `parser` until it fails collecting the outputs.
+#[cfg(feature = "alloc",)]
+fn many<T, E, I, F,>(min: usize, input: I, mut parser: F,) -> Parse<PResult<Vec<T>, E,>, I,>
+  where F: FnMut(I,) -> Parse<PResult<T, E,>, I,>,
+    I: Remaining + Clone, {
+  let mut values = Vec::new();
+  let mut unused = input.clone();
+  loop {
+    let remaining = unused.remaining();
+    match parser(unused.clone(),) {
+      Parse { value: Output(value,), unused: rest, } => {
+        values.push(value,);
+        unused = rest;
+        if unused.remaining() == remaining { break }
+      },
+      Parse { value: Pending(pending,), .. } => return Parse::new(Pending(pending,), input,),
+      Parse { value: Failed(error,), .. } if values.len() < min => return Parse::new(Failed(error,), input,),
+      Parse { value: Failed(_,), .. } => break,
+    }
+  }
+
+  Parse::new(Output(values,), unused,)
+}
+
+/// Replaces a failure with `None`.
+fn opt<T, E, I,>(Parse { value, unused, }: Parse<PResult<T, E,>, I,>,) -> Parse<PResult<Option<T>, E,>, I,> {
+  match value {
+    Output(value,) => Parse::new(Output(Some(value,),), unused,),
+    Pending(pending,) => Parse::new(Pending(pending,), unused,),
+    Failed(_,) => Parse::new(Output(None,), unused,),
+  }
+}
@@ -1,9 +1,13 @@
 //! Transformers of the output type of a parser by sequencing multiple parsers.
 //! 
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
 use crate::{*, combinators::*,};
+#[cfg(feature = "nightly",)]
+use core::ops::Try;
+#[cfg(not(feature = "nightly",),)]
+use crate::stable::StableParse;
 
 /// A parser which maps a applies the output of one parser to the output of another.
 pub type Apply<F, P,> = Pipe<SeqApply<P,>, F,>;
@@ -24,9 +28,9 @@ pub type SeqErr<F, P,> = Pipe<TrySeqErr<F,>, P,>;
 #[derive(Clone, Copy, Default, Debug,)]
 pub struct Pipe<F, P,> {
   /// The mapping to apply.
-  map: F,
+  pub(crate) map: F,
   /// The parser to map.
-  parser: P,
+  pub(crate) parser: P,
 }
 
 impl<F, P,> Pipe<F, P,> {
@@ -35,6 +39,7 @@ impl<F, P,> Pipe<F, P,> {
   pub const fn new(map: F, parser: P,) -> Self { Pipe { map, parser, } }
 }
 
+#[cfg(feature = "nightly",)]
 impl<F, P, T, I,> FnOnce<(I,),> for Pipe<F, P,>
   where F: FnOnce(Parse<P::Value, I,>) -> Parse<T, I,>,
     P: ParserFnOnce<I,>, {
@@ -44,6 +49,7 @@ impl<F, P, T, I,> FnOnce<(I,),> for Pipe<F, P,>
   extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output { (self.map)((self.parser)(input,),) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<F, P, T, I,> FnMut<(I,),> for Pipe<F, P,>
   where F: FnMut(Parse<P::Value, I,>) -> Parse<T, I,>,
     P: ParserFnMut<I,>, {
@@ -51,9 +57,86 @@ impl<F, P, T, I,> FnMut<(I,),> for Pipe<F, P,>
   extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output { (self.map)((self.parser)(input,),) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<F, P, T, I,> Fn<(I,),> for Pipe<F, P,>
   where F: Fn(Parse<P::Value, I,>) -> Parse<T, I,>,
     P: ParserFn<I,>, {
   #[inline]
   extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output { (self.map)((self.parser)(input,),) }
 }
+
+#[cfg(not(feature = "nightly",),)]
+impl<F, P, Q, T, U, E, I,> StableParse<I,> for SeqOk<F, P,>
+  where F: Fn(T,) -> Q,
+    P: StableParse<I, Value = PResult<T, E,>,>,
+    Q: StableParse<I, Value = PResult<U, E,>,>, {
+  type Value = PResult<U, E,>;
+
+  fn parse(&self, input: I,) -> Parse<Self::Value, I,> {
+    match self.parser.parse(input,) {
+      Parse { value: Output(value,), unused, } => (self.map.0)(value,).parse(unused,),
+      Parse { value: Pending(pending,), unused, } => Parse::new(Pending(pending,), unused,),
+      Parse { value: Failed(error,), unused, } => Parse::new(Failed(error,), unused,),
+    }
+  }
+}
+
+/// A parser which returns its input as the unused input if the inner parser fails or is
+/// pending.
+/// 
+/// The first parser of an `or` and each alternative of a grammar are wrapped in `Backtrack`
+/// so a sequence which fails part way through does not consume the input of the next
+/// alternative.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Backtrack<P,>(pub P,);
+
+#[cfg(feature = "nightly",)]
+impl<P, I,> FnOnce<(I,),> for Backtrack<P,>
+  where P: ParserFnOnce<I,>,
+    P::Value: Try,
+    I: Clone, {
+  type Output = Parse<P::Value, I,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output {
+    self.0.parse_once(input.clone(),).backtrack(input,)
+  }
+}
+
+#[cfg(feature = "nightly",)]
+impl<P, I,> FnMut<(I,),> for Backtrack<P,>
+  where P: ParserFnMut<I,>,
+    P::Value: Try,
+    I: Clone, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output {
+    self.0.parse_mut(input.clone(),).backtrack(input,)
+  }
+}
+
+#[cfg(feature = "nightly",)]
+impl<P, I,> Fn<(I,),> for Backtrack<P,>
+  where P: ParserFn<I,>,
+    P::Value: Try,
+    I: Clone, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output {
+    self.0.parse(input.clone(),).backtrack(input,)
+  }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<P, T, E, I,> StableParse<I,> for Backtrack<P,>
+  where P: StableParse<I, Value = PResult<T, E,>,>,
+    I: Clone, {
+  type Value = PResult<T, E,>;
+
+  #[inline]
+  fn parse(&self, input: I,) -> Parse<Self::Value, I,> {
+    match self.0.parse(input.clone(),) {
+      Parse { value: Output(value,), unused, } => Parse::new(Output(value,), unused,),
+      Parse { value, .. } => Parse::new(value, input,),
+    }
+  }
+}
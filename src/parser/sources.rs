@@ -1,13 +1,50 @@
 //! The inbuilt source parsers.
 //! 
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-03-26
+//! Last Moddified --- 2026-10-18
 
 mod eof;
 mod always;
 mod next;
 mod tag;
+#[cfg(feature = "nightly",)]
 mod one_of;
 mod sat;
+#[cfg(feature = "nightly",)]
+mod varint;
+#[cfg(feature = "nightly",)]
+mod bits;
+#[cfg(feature = "nightly",)]
+mod take;
+#[cfg(feature = "nightly",)]
+mod keywords;
+#[cfg(feature = "nightly",)]
+mod class;
+#[cfg(feature = "nightly",)]
+mod trivia;
+#[cfg(feature = "nightly",)]
+mod string;
+#[cfg(feature = "nightly",)]
+mod line;
+#[cfg(feature = "nightly",)]
+mod token_kind;
+mod case_fold;
+#[cfg(feature = "nightly",)]
+mod general_category;
+#[cfg(all(feature = "nightly", feature = "regex",),)]
+mod regex;
 
-pub use self::{eof::*, always::*, next::*, tag::*, one_of::*, sat::*,};
+pub use self::{eof::*, always::*, next::*, tag::*, sat::*,};
+#[cfg(feature = "nightly",)]
+pub use self::{one_of::*, varint::*, bits::*, take::*, keywords::*, class::*, trivia::*, string::*, line::*, token_kind::*,};
+#[cfg(all(feature = "nightly", feature = "regex",),)]
+pub use self::regex::*;
+
+/// The largest character boundary of `input` which is not greater than `index`.
+#[cfg(feature = "nightly",)]
+#[inline]
+fn floor_char_boundary(input: &str, mut index: usize,) -> usize {
+  while !input.is_char_boundary(index,) { index -= 1; }
+
+  index
+}
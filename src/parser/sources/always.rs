@@ -1,7 +1,9 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
 use crate::*;
+#[cfg(not(feature = "nightly",),)]
+use crate::stable::StableParse;
 
 /// A parser which will always produce the same output.
 #[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
@@ -24,6 +26,7 @@ impl<T,> Always<T,> {
   }
 }
 
+#[cfg(feature = "nightly",)]
 impl<T, I,> FnOnce<(I,),> for Always<T,>
   where T: Clone, {
   type Output = Parse<T, I,>;
@@ -32,14 +35,25 @@ impl<T, I,> FnOnce<(I,),> for Always<T,>
   extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output { Parse::new(self.value.clone(), input,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<T, I,> FnMut<(I,),> for Always<T,>
   where T: Clone, {
   #[inline]
   extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output { Parse::new(self.value.clone(), input,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<T, I,> Fn<(I,),> for Always<T,>
   where T: Clone, {
   #[inline]
   extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output { Parse::new(self.value.clone(), input,) }
 }
+
+#[cfg(not(feature = "nightly",),)]
+impl<T, I,> StableParse<I,> for Always<T,>
+  where T: Clone, {
+  type Value = T;
+
+  #[inline]
+  fn parse(&self, input: I,) -> Parse<Self::Value, I,> { Parse::new(self.value.clone(), input,) }
+}
@@ -0,0 +1,123 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{*, parser::bits::Bits,};
+
+/// A parser which accepts a given number of bits from the front of the input.
+/// 
+/// At most 64 bits can be taken, they are returned as the low bits of a `u64`.
+/// 
+/// If there are too few bits the number of bytes needed is returned as pending.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[repr(transparent,)]
+pub struct TakeBits {
+  /// The number of bits to expect.
+  pub count: usize,
+}
+
+impl TakeBits {
+  /// Constructs a new `TakeBits` from `count`.
+  /// 
+  /// # Panics
+  /// 
+  /// If `count` is greater than 64.
+  #[inline]
+  pub const fn new(count: usize,) -> Self {
+    assert!(count <= 64, "`TakeBits` can take at most 64 bits",);
+
+    TakeBits { count, }
+  }
+}
+
+impl<'a,> FnOnce<(Bits<&'a [u8],>,),> for TakeBits {
+  type Output = Parse<PResult<u64, !,>, Bits<&'a [u8],>,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (Bits<&'a [u8],>,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(Bits<&'a [u8],>,),> for TakeBits {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (Bits<&'a [u8],>,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(Bits<&'a [u8],>,),> for TakeBits {
+  extern "rust-call" fn call(&self, (input,): (Bits<&'a [u8],>,),) -> Self::Output {
+    assert!(self.count <= 64, "`TakeBits` can take at most 64 bits",);
+
+    if input.len() < self.count { Parse::new(Pending(input.pending(self.count,),), input,) }
+    else { Parse::from(input.split_bits(self.count,),).map(Output,) }
+  }
+}
+
+/// A parser which accepts a single bit as a `bool`.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct BoolBit;
+
+impl<'a,> FnOnce<(Bits<&'a [u8],>,),> for BoolBit {
+  type Output = Parse<PResult<bool, !,>, Bits<&'a [u8],>,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (Bits<&'a [u8],>,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(Bits<&'a [u8],>,),> for BoolBit {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (Bits<&'a [u8],>,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(Bits<&'a [u8],>,),> for BoolBit {
+  extern "rust-call" fn call(&self, (input,): (Bits<&'a [u8],>,),) -> Self::Output {
+    if input.is_empty() { Parse::new(Pending(1,), input,) }
+    else { Parse::from(input.split_bits(1,),).map(|bit,| Output(bit != 0,),) }
+  }
+}
+
+/// A parser which accepts a specific sequence of bits.
+/// 
+/// If the bits do not match they are returned as the error.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct TagBits {
+  /// The number of bits to expect.
+  pub count: usize,
+  /// The expected bits, held in the low `count` bits.
+  pub tag: u64,
+}
+
+impl TagBits {
+  /// Constructs a new `TagBits` from `count` and `tag`.
+  /// 
+  /// # Panics
+  /// 
+  /// If `count` is greater than 64.
+  #[inline]
+  pub const fn new(count: usize, tag: u64,) -> Self {
+    assert!(count <= 64, "`TagBits` can match at most 64 bits",);
+
+    TagBits { count, tag, }
+  }
+}
+
+impl<'a,> FnOnce<(Bits<&'a [u8],>,),> for TagBits {
+  type Output = Parse<PResult<u64, u64,>, Bits<&'a [u8],>,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (Bits<&'a [u8],>,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(Bits<&'a [u8],>,),> for TagBits {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (Bits<&'a [u8],>,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(Bits<&'a [u8],>,),> for TagBits {
+  extern "rust-call" fn call(&self, (input,): (Bits<&'a [u8],>,),) -> Self::Output {
+    assert!(self.count <= 64, "`TagBits` can match at most 64 bits",);
+
+    if input.len() < self.count { return Parse::new(Pending(input.pending(self.count,),), input,) }
+
+    let (bits, unused,) = input.split_bits(self.count,);
+    if bits == self.tag { Parse::new(Output(bits,), unused,) }
+    else { Parse::new(Failed(bits,), input,) }
+  }
+}
@@ -0,0 +1,258 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::{TokenSet, general_category,};
+use core::cmp::Ordering;
+
+/// A set of bytes stored as a bitset.
+/// 
+/// A `ByteClass` can be used as the set for `OneOf` and `NoneOf` and as the predicate for
+/// `Sat` and `Sat1`.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[repr(transparent,)]
+pub struct ByteClass(pub [u64; 4],);
+
+impl ByteClass {
+  /// The empty class.
+  pub const EMPTY: Self = ByteClass([0; 4],);
+  /// The class of every byte.
+  pub const ANY: Self = ByteClass([!0; 4],);
+  /// The ASCII bytes.
+  pub const ASCII: Self = Self::range(0x00, 0x7f,);
+  /// The ASCII digits.
+  pub const DIGIT: Self = Self::range(b'0', b'9',);
+  /// The ASCII hexadecimal digits.
+  pub const HEX_DIGIT: Self = Self::DIGIT.union(Self::range(b'a', b'f',),).union(Self::range(b'A', b'F',),);
+  /// The ASCII lowercase letters.
+  pub const LOWER: Self = Self::range(b'a', b'z',);
+  /// The ASCII uppercase letters.
+  pub const UPPER: Self = Self::range(b'A', b'Z',);
+  /// The ASCII letters.
+  pub const ALPHA: Self = Self::LOWER.union(Self::UPPER,);
+  /// The ASCII letters and digits.
+  pub const ALPHANUMERIC: Self = Self::ALPHA.union(Self::DIGIT,);
+  /// The ASCII whitespace bytes as defined by `u8::is_ascii_whitespace`.
+  pub const WHITESPACE: Self = Self::from_bytes(b" \t\n\r\x0c",);
+  /// The ASCII punctuation bytes.
+  pub const PUNCTUATION: Self = Self::range(b'!', b'/',).union(Self::range(b':', b'@',),)
+    .union(Self::range(b'[', b'`',),).union(Self::range(b'{', b'~',),);
+
+  /// Constructs a new `ByteClass` containing `bytes`.
+  pub const fn from_bytes(bytes: &[u8],) -> Self {
+    let mut class = Self::EMPTY;
+    let mut i = 0;
+    while i < bytes.len() {
+      class = class.with(bytes[i],);
+      i += 1;
+    }
+
+    class
+  }
+  /// Constructs a new `ByteClass` containing the bytes from `lo` to `hi` inclusive.
+  pub const fn range(lo: u8, hi: u8,) -> Self {
+    let mut class = Self::EMPTY;
+    let mut byte = lo as usize;
+    while byte <= hi as usize {
+      class = class.with(byte as u8,);
+      byte += 1;
+    }
+
+    class
+  }
+  /// Adds `byte` to the class.
+  #[inline]
+  pub const fn with(mut self, byte: u8,) -> Self {
+    self.0[(byte >> 6) as usize] |= 1 << (byte & 63);
+    self
+  }
+  /// The bytes in either class.
+  #[inline]
+  pub const fn union(self, rhs: Self,) -> Self {
+    let (a, b,) = (self.0, rhs.0,);
+    ByteClass([a[0] | b[0], a[1] | b[1], a[2] | b[2], a[3] | b[3],],)
+  }
+  /// The bytes in both classes.
+  #[inline]
+  pub const fn intersection(self, rhs: Self,) -> Self {
+    let (a, b,) = (self.0, rhs.0,);
+    ByteClass([a[0] & b[0], a[1] & b[1], a[2] & b[2], a[3] & b[3],],)
+  }
+  /// The bytes not in the class.
+  #[inline]
+  pub const fn complement(self,) -> Self {
+    let a = self.0;
+    ByteClass([!a[0], !a[1], !a[2], !a[3],],)
+  }
+  /// Checks if `byte` is in the class.
+  #[inline]
+  pub const fn contains(&self, byte: u8,) -> bool { self.0[(byte >> 6) as usize] >> (byte & 63) & 1 != 0 }
+}
+
+impl TokenSet<u8,> for ByteClass {
+  #[inline]
+  fn contains_token(&self, token: &u8,) -> bool { self.contains(*token,) }
+}
+
+/// A set of characters stored as sorted inclusive ranges.
+/// 
+/// A `CharClass` can be used as the set for `OneOf` and `NoneOf` and as the predicate for
+/// `Sat` and `Sat1`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+#[repr(transparent,)]
+pub struct CharClass {
+  /// The ranges of the class.
+  ranges: &'static [(char, char,)],
+}
+
+impl CharClass {
+  /// The ASCII digits.
+  pub const DIGIT: Self = Self::new(&[('0', '9',),],);
+  /// The ASCII hexadecimal digits.
+  pub const HEX_DIGIT: Self = Self::new(&[('0', '9',), ('A', 'F',), ('a', 'f',),],);
+  /// The ASCII letters.
+  pub const ALPHA: Self = Self::new(&[('A', 'Z',), ('a', 'z',),],);
+  /// The ASCII letters and digits.
+  pub const ALPHANUMERIC: Self = Self::new(&[('0', '9',), ('A', 'Z',), ('a', 'z',),],);
+  /// The characters with the Unicode `White_Space` property.
+  pub const WHITESPACE: Self = Self::new(&[
+    ('\u{9}', '\u{d}',), ('\u{20}', '\u{20}',), ('\u{85}', '\u{85}',), ('\u{a0}', '\u{a0}',),
+    ('\u{1680}', '\u{1680}',), ('\u{2000}', '\u{200a}',), ('\u{2028}', '\u{2029}',),
+    ('\u{202f}', '\u{202f}',), ('\u{205f}', '\u{205f}',), ('\u{3000}', '\u{3000}',),
+  ],);
+  /// The characters in the general category `L`.
+  pub const LETTER: Self = Self::new(general_category::LETTER,);
+  /// The characters in the general category `M`.
+  pub const MARK: Self = Self::new(general_category::MARK,);
+  /// The characters in the general category `N`.
+  pub const NUMBER: Self = Self::new(general_category::NUMBER,);
+  /// The characters in the general category `P`.
+  pub const PUNCTUATION: Self = Self::new(general_category::PUNCTUATION,);
+  /// The characters in the general category `S`.
+  pub const SYMBOL: Self = Self::new(general_category::SYMBOL,);
+  /// The characters in the general category `Z`.
+  pub const SEPARATOR: Self = Self::new(general_category::SEPARATOR,);
+
+  /// Constructs a new `CharClass` from `ranges`.
+  /// 
+  /// # Panics
+  /// 
+  /// If the ranges are not sorted, overlap or are empty.
+  pub const fn new(ranges: &'static [(char, char,)],) -> Self {
+    let mut i = 0;
+    while i < ranges.len() {
+      if ranges[i].0 as u32 > ranges[i].1 as u32 { panic!("`CharClass` ranges must not be empty") }
+      if i > 0 && ranges[i - 1].1 as u32 >= ranges[i].0 as u32 {
+        panic!("`CharClass` ranges must be sorted and must not overlap")
+      }
+
+      i += 1;
+    }
+
+    CharClass { ranges, }
+  }
+  /// The ranges of the class.
+  #[inline]
+  pub const fn ranges(&self,) -> &'static [(char, char,)] { self.ranges }
+  /// Checks if `c` is in the class.
+  #[inline]
+  pub fn contains(&self, c: char,) -> bool {
+    self.ranges.binary_search_by(|&(lo, hi,),| {
+      if hi < c { Ordering::Less }
+      else if lo > c { Ordering::Greater }
+      else { Ordering::Equal }
+    },).is_ok()
+  }
+}
+
+impl TokenSet<char,> for CharClass {
+  #[inline]
+  fn contains_token(&self, token: &char,) -> bool { self.contains(*token,) }
+}
+
+/// A class of characters defined by a Unicode property or general category.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum UnicodeClass {
+  /// The characters with the `Alphabetic` property.
+  Alphabetic,
+  /// The characters with the `Lowercase` property.
+  Lowercase,
+  /// The characters with the `Uppercase` property.
+  Uppercase,
+  /// The characters tested by `char::is_numeric`.
+  Numeric,
+  /// The characters which are `Alphabetic` or `Numeric`.
+  Alphanumeric,
+  /// The characters with the `White_Space` property.
+  Whitespace,
+  /// The characters in the general category `Cc`.
+  Control,
+  /// The characters in the general category `L`.
+  Letter,
+  /// The characters in the general category `M`.
+  Mark,
+  /// The characters in the general category `N`.
+  Number,
+  /// The characters in the general category `P`.
+  Punctuation,
+  /// The characters in the general category `S`.
+  Symbol,
+  /// The characters in the general category `Z`.
+  Separator,
+}
+
+impl UnicodeClass {
+  /// Checks if `c` is in the class.
+  #[inline]
+  pub fn contains(&self, c: char,) -> bool {
+    match self {
+      UnicodeClass::Alphabetic => c.is_alphabetic(),
+      UnicodeClass::Lowercase => c.is_lowercase(),
+      UnicodeClass::Uppercase => c.is_uppercase(),
+      UnicodeClass::Numeric => c.is_numeric(),
+      UnicodeClass::Alphanumeric => c.is_alphanumeric(),
+      UnicodeClass::Whitespace => c.is_whitespace(),
+      UnicodeClass::Control => c.is_control(),
+      UnicodeClass::Letter => CharClass::LETTER.contains(c,),
+      UnicodeClass::Mark => CharClass::MARK.contains(c,),
+      UnicodeClass::Number => CharClass::NUMBER.contains(c,),
+      UnicodeClass::Punctuation => CharClass::PUNCTUATION.contains(c,),
+      UnicodeClass::Symbol => CharClass::SYMBOL.contains(c,),
+      UnicodeClass::Separator => CharClass::SEPARATOR.contains(c,),
+    }
+  }
+}
+
+impl TokenSet<char,> for UnicodeClass {
+  #[inline]
+  fn contains_token(&self, token: &char,) -> bool { self.contains(*token,) }
+}
+
+/// Implements the `Fn*` traits for a class so that it can be used as a `Sat` predicate.
+macro_rules! impl_pred {
+  ($($class:ty, $tok:ty, |$t:ident,| $c:expr;)*) => {$(
+    impl<'a,> FnOnce<(usize, $tok,),> for $class {
+      type Output = bool;
+
+      #[inline]
+      extern "rust-call" fn call_once(self, (_, $t,): (usize, $tok,),) -> bool { self.contains($c,) }
+    }
+
+    impl<'a,> FnMut<(usize, $tok,),> for $class {
+      #[inline]
+      extern "rust-call" fn call_mut(&mut self, (_, $t,): (usize, $tok,),) -> bool { self.contains($c,) }
+    }
+
+    impl<'a,> Fn<(usize, $tok,),> for $class {
+      #[inline]
+      extern "rust-call" fn call(&self, (_, $t,): (usize, $tok,),) -> bool { self.contains($c,) }
+    }
+  )*};
+}
+
+impl_pred! {
+  ByteClass, &'a u8, |t,| *t;
+  CharClass, &'a char, |t,| *t;
+  CharClass, char, |t,| t;
+  UnicodeClass, &'a char, |t,| *t;
+  UnicodeClass, char, |t,| t;
+}
@@ -1,7 +1,9 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
 use crate::*;
+#[cfg(not(feature = "nightly",),)]
+use crate::stable::StableParse;
 
 /// A parser which expects an empty input.
 /// 
@@ -9,37 +11,40 @@ use crate::*;
 #[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
 pub struct Eof;
 
+#[cfg(feature = "nightly",)]
 impl<'a, I,> FnOnce<(&'a [I],),> for Eof {
   type Output = Parse<Result<&'a [I; 0], &'a [I]>, &'a [I],>;
 
   #[inline]
-  extern "rust-call" fn call_once(self, (input,): (&'a [I],),) -> Self::Output {
-    Parse::new(
-      if input.is_empty() { Ok(&[]) }
-      else { Err(input) },
-      input,
-    )
-  }
+  extern "rust-call" fn call_once(self, (input,): (&'a [I],),) -> Self::Output { eof(input,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, I,> FnMut<(&'a [I],),> for Eof {
   #[inline]
-  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output {
-    Parse::new(
-      if input.is_empty() { Ok(&[]) }
-      else { Err(input) },
-      input,
-    )
-  }
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output { eof(input,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, I,> Fn<(&'a [I],),> for Eof {
   #[inline]
-  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output {
-    Parse::new(
-      if input.is_empty() { Ok(&[]) }
-      else { Err(input) },
-      input,
-    )
-  }
+  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output { eof(input,) }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<'a, I,> StableParse<&'a [I],> for Eof {
+  type Value = Result<&'a [I; 0], &'a [I]>;
+
+  #[inline]
+  fn parse(&self, input: &'a [I],) -> Parse<Self::Value, &'a [I],> { eof(input,) }
+}
+
+/// Expects `input` to be empty.
+#[inline]
+fn eof<I,>(input: &[I],) -> Parse<Result<&[I; 0], &[I]>, &[I],> {
+  Parse::new(
+    if input.is_empty() { Ok(&[]) }
+    else { Err(input) },
+    input,
+  )
 }
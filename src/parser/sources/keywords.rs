@@ -0,0 +1,192 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+use super::floor_char_boundary;
+
+/// A table of keywords and their associated values.
+pub trait KeywordTable {
+  /// The keyword type.
+  type Key;
+  /// The associated value type.
+  type Value;
+
+  /// The keywords and their values.
+  fn keywords(&self,) -> &[(Self::Key, Self::Value,)];
+}
+
+impl<K, V, const N: usize,> KeywordTable for [(K, V,); N] {
+  type Key = K;
+  type Value = V;
+
+  #[inline]
+  fn keywords(&self,) -> &[(K, V,)] { self }
+}
+
+impl<K, V,> KeywordTable for &'_ [(K, V,)] {
+  type Key = K;
+  type Value = V;
+
+  #[inline]
+  fn keywords(&self,) -> &[(K, V,)] { self }
+}
+
+impl<K, V,> KeywordTable for &'_ mut [(K, V,)] {
+  type Key = K;
+  type Value = V;
+
+  #[inline]
+  fn keywords(&self,) -> &[(K, V,)] { self }
+}
+
+/// A parser which accepts the longest of a set of keywords.
+/// 
+/// The keywords are held sorted with an associated value and are searched as an implicit
+/// trie, narrowing the range of candidate keywords with each token. The matched keyword
+/// is output with a clone of its value.
+/// 
+/// If the input ends while a longer keyword could still match the parse is pending.
+/// If no keyword matches the longest prefix shared with a keyword is returned as the
+/// error.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[repr(transparent,)]
+pub struct KeywordSet<T,> {
+  /// The keywords and their values sorted by keyword.
+  keywords: T,
+}
+
+impl<T,> KeywordSet<T,> {
+  /// Constructs a new `KeywordSet` from `keywords`, sorting them by keyword.
+  pub fn new<K, V,>(mut keywords: T,) -> Self
+    where T: AsMut<[(K, V,)]>,
+      K: Ord, {
+    keywords.as_mut().sort_unstable_by(|(a, _,), (b, _,),| a.cmp(b,),);
+    KeywordSet { keywords, }
+  }
+  /// Constructs a new `KeywordSet` from `keywords` which are already sorted by keyword.
+  /// 
+  /// If `keywords` is not sorted the parser will not find all matches.
+  #[inline]
+  pub const fn sorted(keywords: T,) -> Self { KeywordSet { keywords, } }
+  /// Returns the inner value.
+  #[inline]
+  pub const fn into_inner(self,) -> T {
+    use core::mem::MaybeUninit;
+
+    unsafe { core::ptr::read(MaybeUninit::new(self,).as_ptr() as *const T,) }
+  }
+}
+
+impl<'a, T, I,> FnOnce<(&'a [I],),> for KeywordSet<T,>
+  where T: KeywordTable,
+    T::Key: AsRef<[I]>,
+    T::Value: Clone,
+    I: Ord, {
+  type Output = Parse<PResult<(&'a [I], T::Value,), &'a [I],>, &'a [I],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [I],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a, T, I,> FnMut<(&'a [I],),> for KeywordSet<T,>
+  where T: KeywordTable,
+    T::Key: AsRef<[I]>,
+    T::Value: Clone,
+    I: Ord, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a, T, I,> Fn<(&'a [I],),> for KeywordSet<T,>
+  where T: KeywordTable,
+    T::Key: AsRef<[I]>,
+    T::Value: Clone,
+    I: Ord, {
+  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output {
+    let keywords = self.keywords.keywords();
+
+    match longest_match(keywords, input, T::Key::as_ref,) {
+      Output((index, len,),) => {
+        let (value, unused,) = input.split_at(len,);
+        Parse::new(Output((value, keywords[index].1.clone(),),), unused,)
+      },
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(len,) => Parse::new(Failed(&input[..len],), input,),
+    }
+  }
+}
+
+impl<'a, T,> FnOnce<(&'a str,),> for KeywordSet<T,>
+  where T: KeywordTable,
+    T::Key: AsRef<str>,
+    T::Value: Clone, {
+  type Output = Parse<PResult<(&'a str, T::Value,), &'a str,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a, T,> FnMut<(&'a str,),> for KeywordSet<T,>
+  where T: KeywordTable,
+    T::Key: AsRef<str>,
+    T::Value: Clone, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a, T,> Fn<(&'a str,),> for KeywordSet<T,>
+  where T: KeywordTable,
+    T::Key: AsRef<str>,
+    T::Value: Clone, {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    let keywords = self.keywords.keywords();
+
+    match longest_match(keywords, input.as_bytes(), |key,| key.as_ref().as_bytes(),) {
+      Output((index, len,),) => {
+        let (value, unused,) = input.split_at(len,);
+        Parse::new(Output((value, keywords[index].1.clone(),),), unused,)
+      },
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      //The shared prefix may end partway through a character.
+      Failed(len,) => Parse::new(Failed(&input[..floor_char_boundary(input, len,)],), input,),
+    }
+  }
+}
+
+/// Finds the index and length of the longest keyword at the front of `input`.
+/// 
+/// If no keyword matches the length of the longest prefix shared with a keyword is
+/// returned as the error.
+fn longest_match<K, V, I, F,>(keywords: &[(K, V,)], input: &[I], key: F,) -> PResult<(usize, usize,), usize,>
+  where I: Ord,
+    F: Fn(&K,) -> &[I], {
+  let (mut lo, mut hi,) = (0, keywords.len(),);
+  let mut longest = None;
+  let mut depth = 0;
+  loop {
+    //A keyword which ends here sorts before every keyword it prefixes.
+    if lo < hi && key(&keywords[lo].0,).len() == depth {
+      longest = Some((lo, depth,));
+      while lo < hi && key(&keywords[lo].0,).len() == depth { lo += 1; }
+    }
+    if lo == hi { return longest.map_or(Failed(depth,), Output,) }
+
+    let tok = match input.get(depth,) {
+      Some(tok) => tok,
+      None => {
+        let shortest = keywords[lo..hi].iter()
+          .map(|(k, _,),| key(k,).len(),)
+          .min()
+          .unwrap_or(depth + 1,);
+
+        return Pending(shortest - depth,)
+      },
+    };
+    let range = &keywords[lo..hi];
+    hi = lo + range.partition_point(|(k, _,),| key(k,)[depth] <= *tok,);
+    lo += range.partition_point(|(k, _,),| key(k,)[depth] < *tok,);
+    if lo == hi { return longest.map_or(Failed(depth,), Output,) }
+
+    depth += 1;
+  }
+}
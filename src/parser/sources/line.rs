@@ -0,0 +1,286 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+use memchr::memchr2;
+
+/// A parser which accepts a line ending.
+/// 
+/// `\n` and `\r\n` are always accepted, a lone `\r` is accepted if enabled. If the input
+/// ends with a `\r` the parse is pending as it could be followed by a `\n`. If the input
+/// does not begin with a line ending the first token is returned as the error.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct LineEnding {
+  /// Whether a lone `\r` ends a line.
+  pub lone_cr: bool,
+}
+
+impl LineEnding {
+  /// Constructs a new `LineEnding` accepting `\n` and `\r\n`.
+  #[inline]
+  pub const fn new() -> Self { LineEnding { lone_cr: false, } }
+  /// Accepts a lone `\r` as a line ending.
+  #[inline]
+  pub const fn lone_cr(mut self,) -> Self {
+    self.lone_cr = true;
+    self
+  }
+}
+
+impl<'a,> FnOnce<(&'a [u8],),> for LineEnding {
+  type Output = Parse<PResult<&'a [u8], &'a [u8; 1],>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a [u8],),> for LineEnding {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a [u8],),> for LineEnding {
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output {
+    match line_ending(input, self.lone_cr,) {
+      Output(len,) => Parse::from(input.split_at(len,),).map(Output,),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(()) => Parse::new(Failed(core::array::from_ref(&input[0],),), input,),
+    }
+  }
+}
+
+impl<'a,> FnOnce<(&'a str,),> for LineEnding {
+  type Output = Parse<PResult<&'a str, char,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a str,),> for LineEnding {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a str,),> for LineEnding {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    match line_ending(input.as_bytes(), self.lone_cr,) {
+      Output(len,) => Parse::from(input.split_at(len,),).map(Output,),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(()) => Parse::new(Failed(input.chars().next().unwrap_or_default(),), input,),
+    }
+  }
+}
+
+/// A parser which accepts tokens up to the next line ending.
+/// 
+/// The line ending is not consumed. If the input ends before a line ending, or with a
+/// `\r`, the parse is pending.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct NotLineEnding {
+  /// Whether a lone `\r` ends a line.
+  pub lone_cr: bool,
+}
+
+impl NotLineEnding {
+  /// Constructs a new `NotLineEnding` stopping at `\n` and `\r\n`.
+  #[inline]
+  pub const fn new() -> Self { NotLineEnding { lone_cr: false, } }
+  /// Stops at a lone `\r`.
+  #[inline]
+  pub const fn lone_cr(mut self,) -> Self {
+    self.lone_cr = true;
+    self
+  }
+}
+
+impl<'a,> FnOnce<(&'a [u8],),> for NotLineEnding {
+  type Output = Parse<PResult<&'a [u8], !,>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a [u8],),> for NotLineEnding {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a [u8],),> for NotLineEnding {
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output {
+    match find_line_ending(input, self.lone_cr,) {
+      Output((index, _,),) => Parse::from(input.split_at(index,),).map(Output,),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(never,) => never,
+    }
+  }
+}
+
+impl<'a,> FnOnce<(&'a str,),> for NotLineEnding {
+  type Output = Parse<PResult<&'a str, !,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a str,),> for NotLineEnding {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a str,),> for NotLineEnding {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    match find_line_ending(input.as_bytes(), self.lone_cr,) {
+      Output((index, _,),) => Parse::from(input.split_at(index,),).map(Output,),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(never,) => never,
+    }
+  }
+}
+
+/// A parser which accepts a line and its line ending.
+/// 
+/// The line is output without its line ending. If the input ends before a line ending,
+/// or with a `\r`, the parse is pending.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct TerminatedLine {
+  /// Whether a lone `\r` ends a line.
+  pub lone_cr: bool,
+}
+
+impl TerminatedLine {
+  /// Constructs a new `TerminatedLine` ending at `\n` and `\r\n`.
+  #[inline]
+  pub const fn new() -> Self { TerminatedLine { lone_cr: false, } }
+  /// Ends lines at a lone `\r`.
+  #[inline]
+  pub const fn lone_cr(mut self,) -> Self {
+    self.lone_cr = true;
+    self
+  }
+}
+
+impl<'a,> FnOnce<(&'a [u8],),> for TerminatedLine {
+  type Output = Parse<PResult<&'a [u8], !,>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a [u8],),> for TerminatedLine {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a [u8],),> for TerminatedLine {
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output {
+    match find_line_ending(input, self.lone_cr,) {
+      Output((index, len,),) => Parse::new(Output(&input[..index],), &input[index + len..],),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(never,) => never,
+    }
+  }
+}
+
+impl<'a,> FnOnce<(&'a str,),> for TerminatedLine {
+  type Output = Parse<PResult<&'a str, !,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a str,),> for TerminatedLine {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a str,),> for TerminatedLine {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    match find_line_ending(input.as_bytes(), self.lone_cr,) {
+      Output((index, len,),) => Parse::new(Output(&input[..index],), &input[index + len..],),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(never,) => never,
+    }
+  }
+}
+
+/// An iterator which applies a parser to each complete line of an input.
+/// 
+/// Each line is passed to the parser without its line ending and the output of the
+/// parser is yielded, any part of the line the parser did not use is dropped. Iteration
+/// stops when no complete line remains, the rest of the input can then be taken with
+/// `into_unused`.
+#[derive(Clone, Copy, Debug,)]
+pub struct Lines<P, I,> {
+  /// The parser to apply to each line.
+  parser: P,
+  /// The remaining input.
+  input: I,
+  /// The parser which splits the lines.
+  line: TerminatedLine,
+}
+
+impl<P, I,> Lines<P, I,> {
+  /// Constructs a new `Lines` applying `parser` to the lines of `input`.
+  #[inline]
+  pub const fn new(parser: P, input: I,) -> Self { Lines { parser, input, line: TerminatedLine::new(), } }
+  /// Ends lines at a lone `\r`.
+  #[inline]
+  pub const fn lone_cr(mut self,) -> Self {
+    self.line = self.line.lone_cr();
+    self
+  }
+  /// The input which has not been split into lines.
+  #[inline]
+  pub const fn unused(&self,) -> &I { &self.input }
+  /// Returns the input which has not been split into lines.
+  #[inline]
+  pub fn into_unused(self,) -> I { self.input }
+}
+
+impl<P, I,> Iterator for Lines<P, I,>
+  where P: ParserFnMut<I,>,
+    TerminatedLine: ParserFn<I, Value = PResult<I, !,>,>,
+    I: Clone, {
+  type Item = P::Value;
+
+  fn next(&mut self,) -> Option<Self::Item> {
+    let Parse { value, unused, } = self.line.parse(self.input.clone(),);
+    match value {
+      Output(line,) => {
+        self.input = unused;
+        Some(self.parser.parse_mut(line,).value,)
+      },
+      _ => None,
+    }
+  }
+}
+
+/// Finds the length of the line ending at the front of `input`.
+fn line_ending(input: &[u8], lone_cr: bool,) -> PResult<usize, (),> {
+  match input {
+    [b'\n', ..] => Output(1,),
+    [b'\r', b'\n', ..] => Output(2,),
+    [] | [b'\r',] => Pending(1,),
+    [b'\r', ..] if lone_cr => Output(1,),
+    _ => Failed((),),
+  }
+}
+
+/// Finds the index and length of the first line ending in `input`.
+fn find_line_ending(input: &[u8], lone_cr: bool,) -> PResult<(usize, usize,), !,> {
+  let mut from = 0;
+  while let Some(index,) = memchr2(b'\n', b'\r', &input[from..],) {
+    let index = from + index;
+    if input[index] == b'\n' { return Output((index, 1,),) }
+
+    match input.get(index + 1,) {
+      Some(b'\n',) => return Output((index, 2,),),
+      //The `\r` may yet be followed by a `\n`.
+      None => return Pending(1,),
+      Some(_,) if lone_cr => return Output((index, 1,),),
+      Some(_,) => from = index + 1,
+    }
+  }
+
+  Pending(1,)
+}
@@ -1,7 +1,9 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
-use crate::*;
+use crate::{*, result::Never,};
+#[cfg(not(feature = "nightly",),)]
+use crate::stable::StableParse;
 use core::convert::TryFrom;
 
 /// A parser which accepts a given number of tokens from the front of the input.
@@ -18,26 +20,32 @@ impl Next {
   pub const fn new(count: usize,) -> Self { Next { count, } }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, I,> FnOnce<(&'a [I],),> for Next {
   type Output = Parse<PResult<&'a [I], !,>, &'a [I],>;
 
   #[inline]
-  extern "rust-call" fn call_once(self, (input,): (&'a [I],),) -> Self::Output { (&self)(input,) }
+  extern "rust-call" fn call_once(self, (input,): (&'a [I],),) -> Self::Output { next(self.count, input,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, I,> FnMut<(&'a [I],),> for Next {
   #[inline]
-  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output { (&*self)(input,) }
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output { next(self.count, input,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, I,> Fn<(&'a [I],),> for Next {
   #[inline]
-  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output {
-    match self.count.checked_sub(input.len(),) {
-      Some(pending) if pending > 0 => Parse::new(Pending(pending,), input,),
-      _ => Parse::from(input.split_at(self.count,),).map(Output,),
-    }
-  }
+  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output { next(self.count, input,) }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<'a, I,> StableParse<&'a [I],> for Next {
+  type Value = PResult<&'a [I], Never,>;
+
+  #[inline]
+  fn parse(&self, input: &'a [I],) -> Parse<Self::Value, &'a [I],> { next(self.count, input,) }
 }
 
 impl<const N: usize,> From<NextN<N,>> for Next {
@@ -59,34 +67,32 @@ impl<const N: usize,> NextN<N,> {
   pub const NEXT: Next = Next::new(N,);
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, I, const N: usize,> FnOnce<(&'a [I],),> for NextN<N,> {
   type Output = Parse<PResult<&'a [I; N], !,>, &'a [I],>;
 
   #[inline]
-  extern "rust-call" fn call_once(self, (input,): (&'a [I],),) -> Self::Output { (&self)(input,) }
+  extern "rust-call" fn call_once(self, (input,): (&'a [I],),) -> Self::Output { next_n(input,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, I, const N: usize,> FnMut<(&'a [I],),> for NextN<N,> {
   #[inline]
-  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output { (&*self)(input,) }
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output { next_n(input,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, I, const N: usize,> Fn<(&'a [I],),> for NextN<N,> {
   #[inline]
-  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output {
-    match N.checked_sub(input.len(),) {
-      Some(pending) if pending > 0 => Parse::new(Pending(pending,), input,),
-      _ => {
-        let (value, unused,) = input.split_at(N,);
-        Parse::new(
-          Output(unsafe {
-            &*(value.as_ptr() as *const [I; N])
-          },),
-          unused,
-        )
-      },
-    }
-  }
+  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output { next_n(input,) }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<'a, I, const N: usize,> StableParse<&'a [I],> for NextN<N,> {
+  type Value = PResult<&'a [I; N], Never,>;
+
+  #[inline]
+  fn parse(&self, input: &'a [I],) -> Parse<Self::Value, &'a [I],> { next_n(input,) }
 }
 
 impl<const N: usize,> TryFrom<Next> for NextN<N,> {
@@ -107,3 +113,27 @@ impl<const N: usize,> PartialEq<Next> for NextN<N,> {
   #[inline]
   fn eq(&self, rhs: &Next,) -> bool { N == rhs.count }
 }
+
+/// Accepts the next `count` tokens of `input`.
+fn next<I,>(count: usize, input: &[I],) -> Parse<PResult<&[I], Never,>, &[I],> {
+  match count.checked_sub(input.len(),) {
+    Some(pending) if pending > 0 => Parse::new(Pending(pending,), input,),
+    _ => Parse::from(input.split_at(count,),).map(Output,),
+  }
+}
+
+/// Accepts the next `N` tokens of `input` as an array.
+fn next_n<I, const N: usize,>(input: &[I],) -> Parse<PResult<&[I; N], Never,>, &[I],> {
+  match N.checked_sub(input.len(),) {
+    Some(pending) if pending > 0 => Parse::new(Pending(pending,), input,),
+    _ => {
+      let (value, unused,) = input.split_at(N,);
+      Parse::new(
+        Output(unsafe {
+          &*(value.as_ptr() as *const [I; N])
+        },),
+        unused,
+      )
+    },
+  }
+}
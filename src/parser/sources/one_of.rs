@@ -1,8 +1,23 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
 use crate::*;
 
+/// A set of tokens which can be checked for membership.
+/// 
+/// Any sequence of tokens is a set containing its elements.
+pub trait TokenSet<I,> {
+  /// Checks if `token` is in the set.
+  fn contains_token(&self, token: &I,) -> bool;
+}
+
+impl<T, I,> TokenSet<I,> for T
+  where T: AsRef<[I]>,
+    I: PartialEq, {
+  #[inline]
+  fn contains_token(&self, token: &I,) -> bool { self.as_ref().contains(token,) }
+}
+
 /// A parser which accepts the next token using a set of allowed tokens.
 /// 
 /// If an unexpected token occurs the it is returned as the error.
@@ -27,8 +42,7 @@ impl<T,> OneOf<T,> {
 }
 
 impl<'a, T, I,> FnOnce<(&'a [I],),> for OneOf<T,>
-  where T: AsRef<[I]>,
-    I: PartialEq, {
+  where T: TokenSet<I,>, {
   type Output = Parse<PResult<&'a [I; 1], &'a [I; 1],>, &'a [I],>;
 
   #[inline]
@@ -36,22 +50,20 @@ impl<'a, T, I,> FnOnce<(&'a [I],),> for OneOf<T,>
 }
 
 impl<'a, T, I,> FnMut<(&'a [I],),> for OneOf<T,>
-  where T: AsRef<[I]>,
-    I: PartialEq, {
+  where T: TokenSet<I,>, {
   #[inline]
   extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output { (&*self)(input,) }
 }
 
 impl<'a, T, I,> Fn<(&'a [I],),> for OneOf<T,>
-  where T: AsRef<[I]>,
-    I: PartialEq, {
+  where T: TokenSet<I,>, {
   extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output {
     match input.split_first() {
       None => Parse::new(Pending(1,), input,),
       Some((tok, unused,)) => {
         let tok = core::array::from_ref(tok,);
 
-        if self.one_of.as_ref().contains(&tok[0],) {
+        if self.one_of.contains_token(&tok[0],) {
           Parse::new(Output(tok,), unused,)
         } else { Parse::new(Failed(tok,), input,) }
       },
@@ -59,6 +71,34 @@ impl<'a, T, I,> Fn<(&'a [I],),> for OneOf<T,>
   }
 }
 
+impl<'a, T,> FnOnce<(&'a str,),> for OneOf<T,>
+  where T: TokenSet<char,>, {
+  type Output = Parse<PResult<char, char,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a, T,> FnMut<(&'a str,),> for OneOf<T,>
+  where T: TokenSet<char,>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a, T,> Fn<(&'a str,),> for OneOf<T,>
+  where T: TokenSet<char,>, {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    match input.chars().next() {
+      None => Parse::new(Pending(1,), input,),
+      Some(tok) => {
+        if self.one_of.contains_token(&tok,) {
+          Parse::new(Output(tok,), &input[tok.len_utf8()..],)
+        } else { Parse::new(Failed(tok,), input,) }
+      },
+    }
+  }
+}
+
 /// A parser which accepts the next token using a set of forbidden tokens.
 /// 
 /// If an unexpected token occurs the it is returned as the error.
@@ -83,8 +123,7 @@ impl<T,> NoneOf<T,> {
 }
 
 impl<'a, T, I,> FnOnce<(&'a [I],),> for NoneOf<T,>
-  where T: AsRef<[I]>,
-    I: PartialEq, {
+  where T: TokenSet<I,>, {
   type Output = Parse<PResult<&'a [I; 1], &'a [I; 1],>, &'a [I],>;
 
   #[inline]
@@ -92,25 +131,51 @@ impl<'a, T, I,> FnOnce<(&'a [I],),> for NoneOf<T,>
 }
 
 impl<'a, T, I,> FnMut<(&'a [I],),> for NoneOf<T,>
-  where T: AsRef<[I]>,
-    I: PartialEq, {
+  where T: TokenSet<I,>, {
   #[inline]
   extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output { (&*self)(input,) }
 }
 
 impl<'a, T, I,> Fn<(&'a [I],),> for NoneOf<T,>
-  where T: AsRef<[I]>,
-    I: PartialEq, {
+  where T: TokenSet<I,>, {
   extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output {
     match input.split_first() {
       None => Parse::new(Pending(1,), input,),
       Some((tok, unused,)) => {
         let tok = core::array::from_ref(tok,);
 
-        if self.none_of.as_ref().contains(&tok[0],) {
+        if self.none_of.contains_token(&tok[0],) {
           Parse::new(Failed(tok,), input,)
         } else { Parse::new(Output(tok,), unused,) }
       },
     }
   }
 }
+
+impl<'a, T,> FnOnce<(&'a str,),> for NoneOf<T,>
+  where T: TokenSet<char,>, {
+  type Output = Parse<PResult<char, char,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a, T,> FnMut<(&'a str,),> for NoneOf<T,>
+  where T: TokenSet<char,>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a, T,> Fn<(&'a str,),> for NoneOf<T,>
+  where T: TokenSet<char,>, {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    match input.chars().next() {
+      None => Parse::new(Pending(1,), input,),
+      Some(tok) => {
+        if self.none_of.contains_token(&tok,) {
+          Parse::new(Failed(tok,), input,)
+        } else { Parse::new(Output(tok,), &input[tok.len_utf8()..],) }
+      },
+    }
+  }
+}
@@ -0,0 +1,255 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+use super::floor_char_boundary;
+use alloc::vec::Vec;
+use core::ops::{Index, Range,};
+use regex_automata::{
+  Anchored, Input,
+  dfa::{Automaton, StartKind, dense::{self, DFA,},},
+  meta,
+  util::{captures, start, primitives::StateID,},
+};
+
+/// The error produced when a regex fails to compile.
+#[derive(Clone, Debug,)]
+pub enum RegexError {
+  /// The matching automaton could not be built.
+  Dfa(dense::BuildError,),
+  /// The capturing regex could not be built.
+  Captures(meta::BuildError,),
+}
+
+/// A parser which accepts the match of a regex anchored at the front of the input.
+/// 
+/// The regex is compiled to a DFA which is run over the input. If the input ends while
+/// a longer match is still possible, or before the outcome of the match is known, the
+/// parse is pending. Assertions about the end of the input such as `$` can only be
+/// decided by further input.
+/// 
+/// If the regex does not match the prefix of the input accepted before the match became
+/// impossible is returned as the error.
+#[derive(Clone, Debug,)]
+pub struct Regex {
+  /// The anchored DFA for the regex.
+  dfa: DFA<Vec<u32>,>,
+}
+
+impl Regex {
+  /// Constructs a new `Regex` from `pattern`.
+  /// 
+  /// Unicode word boundaries cannot be compiled to a DFA, `(?-u:\b)` can be used instead.
+  pub fn new(pattern: &str,) -> Result<Self, RegexError> {
+    dense::Builder::new()
+      .configure(dense::Config::new().start_kind(StartKind::Anchored,),)
+      .build(pattern,)
+      .map(|dfa,| Regex { dfa, },)
+      .map_err(RegexError::Dfa,)
+  }
+  /// Finds the length of the match at the front of `input`.
+  /// 
+  /// If there is no match the length of the prefix accepted before the match became
+  /// impossible is returned as the error.
+  fn find(&self, input: &[u8],) -> PResult<usize, usize,> {
+    let dfa = &self.dfa;
+    let config = start::Config::new().anchored(Anchored::Yes,);
+    let mut state = match dfa.start_state(&config,) {
+      Ok(state) => state,
+      Err(_) => return Failed(0,),
+    };
+    let mut last = None;
+    for (index, &byte,) in input.iter().enumerate() {
+      state = dfa.next_state(state, byte,);
+      //Matches are reported one byte after they end.
+      if dfa.is_match_state(state,) { last = Some(index,) }
+      else if dfa.is_dead_state(state,) { return last.map_or(Failed(index,), Output,) }
+    }
+
+    //The outcome is only known if every byte which could follow agrees on it.
+    let mut outcome = None;
+    for byte in dfa.byte_classes().representatives(..=u8::MAX,).filter_map(|unit,| unit.as_u8(),) {
+      let next = dfa.next_state(state, byte,);
+      let found = if dfa.is_dead_state(next,) { last }
+        else if dfa.is_match_state(next,) && self.is_final(next,) { Some(input.len(),) }
+        else { return Pending(1,) };
+
+      match outcome {
+        None => outcome = Some(found,),
+        Some(outcome,) if outcome != found => return Pending(1,),
+        Some(_,) => {},
+      }
+    }
+
+    match outcome.flatten() {
+      Some(len,) => Output(len,),
+      None => Failed(input.len(),),
+    }
+  }
+  /// Checks if every byte leads from `state` to the dead state.
+  fn is_final(&self, state: StateID,) -> bool {
+    let dfa = &self.dfa;
+
+    dfa.byte_classes().representatives(..=u8::MAX,)
+      .filter_map(|unit,| unit.as_u8(),)
+      .all(|byte,| dfa.is_dead_state(dfa.next_state(state, byte,),),)
+  }
+}
+
+impl<'a,> FnOnce<(&'a [u8],),> for Regex {
+  type Output = Parse<PResult<&'a [u8], &'a [u8],>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a [u8],),> for Regex {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a [u8],),> for Regex {
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output {
+    match self.find(input,) {
+      Output(len,) => Parse::from(input.split_at(len,),).map(Output,),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(len,) => Parse::new(Failed(&input[..len],), input,),
+    }
+  }
+}
+
+impl<'a,> FnOnce<(&'a str,),> for Regex {
+  type Output = Parse<PResult<&'a str, &'a str,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a str,),> for Regex {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a str,),> for Regex {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    match self.find(input.as_bytes(),) {
+      Output(len,) => Parse::from(input.split_at(len,),).map(Output,),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(len,) => Parse::new(Failed(&input[..floor_char_boundary(input, len,)],), input,),
+    }
+  }
+}
+
+/// The capture groups of a regex match.
+#[derive(Clone, Debug,)]
+pub struct Captures<'a, I: ?Sized,> {
+  /// The input which was matched.
+  input: &'a I,
+  /// The spans of the groups.
+  captures: captures::Captures,
+}
+
+impl<'a, I: ?Sized,> Captures<'a, I,>
+  where I: Index<Range<usize>, Output = I>, {
+  /// The whole match.
+  #[inline]
+  pub fn get_match(&self,) -> &'a I { self.get(0,).unwrap_or(&self.input[0..0],) }
+  /// The group at `index`, if it participated in the match.
+  /// 
+  /// The group at index `0` is the whole match.
+  #[inline]
+  pub fn get(&self, index: usize,) -> Option<&'a I> {
+    let input = self.input;
+
+    self.captures.get_group(index,).map(|span,| &input[span.range()],)
+  }
+  /// The group called `name`, if it participated in the match.
+  #[inline]
+  pub fn name(&self, name: &str,) -> Option<&'a I> {
+    let input = self.input;
+
+    self.captures.get_group_by_name(name,).map(|span,| &input[span.range()],)
+  }
+  /// The number of groups including the whole match.
+  #[inline]
+  pub fn len(&self,) -> usize { self.captures.group_len() }
+  /// Checks if there are no groups.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.len() == 0 }
+}
+
+/// A parser which accepts the match of a regex anchored at the front of the input and
+/// outputs its capture groups.
+/// 
+/// The match is found as with `Regex` and its groups are then resolved by a second,
+/// capturing regex.
+#[derive(Clone, Debug,)]
+pub struct RegexCaptures {
+  /// The regex which finds the match.
+  regex: Regex,
+  /// The regex which resolves the capture groups.
+  captures: meta::Regex,
+}
+
+impl RegexCaptures {
+  /// Constructs a new `RegexCaptures` from `pattern`.
+  pub fn new(pattern: &str,) -> Result<Self, RegexError> {
+    let regex = Regex::new(pattern,)?;
+    let captures = meta::Regex::new(pattern,).map_err(RegexError::Captures,)?;
+
+    Ok(RegexCaptures { regex, captures, })
+  }
+  /// Resolves the capture groups of the match of length `len` at the front of `input`.
+  fn captures<'a, I: ?Sized,>(&self, input: &'a I, len: usize,) -> Captures<'a, I,>
+    where I: AsRef<[u8]>, {
+    let mut captures = self.captures.create_captures();
+    let search = Input::new(input,).span(0..len,).anchored(Anchored::Yes,);
+    self.captures.search_captures(&search, &mut captures,);
+
+    Captures { input, captures, }
+  }
+}
+
+impl<'a,> FnOnce<(&'a [u8],),> for RegexCaptures {
+  type Output = Parse<PResult<Captures<'a, [u8],>, &'a [u8],>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a [u8],),> for RegexCaptures {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a [u8],),> for RegexCaptures {
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output {
+    match self.regex.find(input,) {
+      Output(len,) => Parse::new(Output(self.captures(input, len,),), &input[len..],),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(len,) => Parse::new(Failed(&input[..len],), input,),
+    }
+  }
+}
+
+impl<'a,> FnOnce<(&'a str,),> for RegexCaptures {
+  type Output = Parse<PResult<Captures<'a, str,>, &'a str,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a str,),> for RegexCaptures {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a str,),> for RegexCaptures {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    match self.regex.find(input.as_bytes(),) {
+      Output(len,) => Parse::new(Output(self.captures(input, len,),), &input[len..],),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(len,) => Parse::new(Failed(&input[..floor_char_boundary(input, len,)],), input,),
+    }
+  }
+}
@@ -1,7 +1,9 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
-use crate::*;
+use crate::{*, result::Never,};
+#[cfg(not(feature = "nightly",),)]
+use crate::stable::StableParse;
 
 /// A parser which accepts tokens as long as they satisfy a predicate.
 /// 
@@ -26,36 +28,68 @@ impl<F,> Sat<F,> {
   }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, F, I,> FnOnce<(&'a [I],),> for Sat<F,>
   where F: FnMut(usize, &'a I,) -> bool, {
   type Output = Parse<PResult<&'a [I], !,>, &'a [I],>;
 
   #[inline]
-  extern "rust-call" fn call_once(mut self, (input,): (&'a [I],),) -> Self::Output { (&mut self)(input,) }
+  extern "rust-call" fn call_once(mut self, (input,): (&'a [I],),) -> Self::Output { sat(&mut self.pred, input,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, F, I,> FnMut<(&'a [I],),> for Sat<F,>
   where F: FnMut(usize, &'a I,) -> bool, {
-  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output {
-    let matched = input.iter().enumerate()
-      .take_while(|&(i, t),| (self.pred)(i, t,),)
-      .count();
-
-    if matched < input.len() { Parse::from(input.split_at(matched,),).map(Output,) }
-    else { Parse::new(Pending(1,), input,) }
-  }
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output { sat(&mut self.pred, input,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, F, I,> Fn<(&'a [I],),> for Sat<F,>
   where F: Fn(usize, &'a I,) -> bool, {
-  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output {
-    let matched = input.iter().enumerate()
-      .take_while(|&(i, t),| (self.pred)(i, t,),)
-      .count();
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output { sat(&self.pred, input,) }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<'a, F, I,> StableParse<&'a [I],> for Sat<F,>
+  where F: Fn(usize, &'a I,) -> bool, {
+  type Value = PResult<&'a [I], Never,>;
 
-    if matched < input.len() { Parse::from(input.split_at(matched,),).map(Output,) }
-    else { Parse::new(Pending(1,), input,) }
-  }
+  #[inline]
+  fn parse(&self, input: &'a [I],) -> Parse<Self::Value, &'a [I],> { sat(&self.pred, input,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<'a, F,> FnOnce<(&'a str,),> for Sat<F,>
+  where F: FnMut(usize, char,) -> bool, {
+  type Output = Parse<PResult<&'a str, !,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(mut self, (input,): (&'a str,),) -> Self::Output { sat_str(&mut self.pred, input,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<'a, F,> FnMut<(&'a str,),> for Sat<F,>
+  where F: FnMut(usize, char,) -> bool, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { sat_str(&mut self.pred, input,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<'a, F,> Fn<(&'a str,),> for Sat<F,>
+  where F: Fn(usize, char,) -> bool, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output { sat_str(&self.pred, input,) }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<'a, F,> StableParse<&'a str,> for Sat<F,>
+  where F: Fn(usize, char,) -> bool, {
+  type Value = PResult<&'a str, Never,>;
+
+  #[inline]
+  fn parse(&self, input: &'a str,) -> Parse<Self::Value, &'a str,> { sat_str(&self.pred, input,) }
 }
 
 /// A parser which accepts tokens as long as they satisfy a predicate requiring at least
@@ -82,38 +116,111 @@ impl<F,> Sat1<F,> {
   }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, F, I,> FnOnce<(&'a [I],),> for Sat1<F,>
   where F: FnMut(usize, &'a I,) -> bool, {
   type Output = Parse<PResult<&'a [I], &'a [I; 1],>, &'a [I],>;
 
   #[inline]
-  extern "rust-call" fn call_once(mut self, (input,): (&'a [I],),) -> Self::Output { (&mut self)(input,) }
+  extern "rust-call" fn call_once(mut self, (input,): (&'a [I],),) -> Self::Output { sat1(&mut self.pred, input,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, F, I,> FnMut<(&'a [I],),> for Sat1<F,>
   where F: FnMut(usize, &'a I,) -> bool, {
-  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output {
-    let matched = input.iter().enumerate()
-      .take_while(|&(i, t),| (self.pred)(i, t,),)
-      .count();
-
-    if matched < input.len() {
-      if matched == 0 { Parse::new(Failed(core::array::from_ref(&input[0],),), input,) }
-      else { Parse::from(input.split_at(matched,),).map(Output,) }
-    } else { Parse::new(Pending(1,), input,) }
-  }
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output { sat1(&mut self.pred, input,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, F, I,> Fn<(&'a [I],),> for Sat1<F,>
   where F: Fn(usize, &'a I,) -> bool, {
-  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output {
-    let matched = input.iter().enumerate()
-      .take_while(|&(i, t),| (self.pred)(i, t,),)
-      .count();
-
-    if matched < input.len() {
-      if matched == 0 { Parse::new(Failed(core::array::from_ref(&input[0],),), input,) }
-      else { Parse::from(input.split_at(matched,),).map(Output,) }
-    } else { Parse::new(Pending(1,), input,) }
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output { sat1(&self.pred, input,) }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<'a, F, I,> StableParse<&'a [I],> for Sat1<F,>
+  where F: Fn(usize, &'a I,) -> bool, {
+  type Value = PResult<&'a [I], &'a [I; 1],>;
+
+  #[inline]
+  fn parse(&self, input: &'a [I],) -> Parse<Self::Value, &'a [I],> { sat1(&self.pred, input,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<'a, F,> FnOnce<(&'a str,),> for Sat1<F,>
+  where F: FnMut(usize, char,) -> bool, {
+  type Output = Parse<PResult<&'a str, char,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(mut self, (input,): (&'a str,),) -> Self::Output { sat1_str(&mut self.pred, input,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<'a, F,> FnMut<(&'a str,),> for Sat1<F,>
+  where F: FnMut(usize, char,) -> bool, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { sat1_str(&mut self.pred, input,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<'a, F,> Fn<(&'a str,),> for Sat1<F,>
+  where F: Fn(usize, char,) -> bool, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output { sat1_str(&self.pred, input,) }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<'a, F,> StableParse<&'a str,> for Sat1<F,>
+  where F: Fn(usize, char,) -> bool, {
+  type Value = PResult<&'a str, char,>;
+
+  #[inline]
+  fn parse(&self, input: &'a str,) -> Parse<Self::Value, &'a str,> { sat1_str(&self.pred, input,) }
+}
+
+/// Accepts the tokens of `input` which satisfy `pred`.
+fn sat<'a, I,>(mut pred: impl FnMut(usize, &'a I,) -> bool, input: &'a [I],) -> Parse<PResult<&'a [I], Never,>, &'a [I],> {
+  let matched = input.iter().enumerate()
+    .take_while(|&(i, t),| pred(i, t,),)
+    .count();
+
+  if matched < input.len() { Parse::from(input.split_at(matched,),).map(Output,) }
+  else { Parse::new(Pending(1,), input,) }
+}
+
+/// Accepts the characters of `input` which satisfy `pred`.
+fn sat_str(mut pred: impl FnMut(usize, char,) -> bool, input: &str,) -> Parse<PResult<&str, Never,>, &str,> {
+  let unmatched = input.char_indices().enumerate()
+    .find(|&(i, (_, c,),),| !pred(i, c,),);
+
+  match unmatched {
+    Some((_, (index, _,),)) => Parse::from(input.split_at(index,),).map(Output,),
+    None => Parse::new(Pending(1,), input,),
+  }
+}
+
+/// Accepts at least one of the tokens of `input` which satisfy `pred`.
+fn sat1<'a, I,>(mut pred: impl FnMut(usize, &'a I,) -> bool, input: &'a [I],) -> Parse<PResult<&'a [I], &'a [I; 1],>, &'a [I],> {
+  let matched = input.iter().enumerate()
+    .take_while(|&(i, t),| pred(i, t,),)
+    .count();
+
+  if matched < input.len() {
+    if matched == 0 { Parse::new(Failed(core::array::from_ref(&input[0],),), input,) }
+    else { Parse::from(input.split_at(matched,),).map(Output,) }
+  } else { Parse::new(Pending(1,), input,) }
+}
+
+/// Accepts at least one of the characters of `input` which satisfy `pred`.
+fn sat1_str(mut pred: impl FnMut(usize, char,) -> bool, input: &str,) -> Parse<PResult<&str, char,>, &str,> {
+  let unmatched = input.char_indices().enumerate()
+    .find(|&(i, (_, c,),),| !pred(i, c,),);
+
+  match unmatched {
+    Some((0, (_, c,),)) => Parse::new(Failed(c,), input,),
+    Some((_, (index, _,),)) => Parse::from(input.split_at(index,),).map(Output,),
+    None => Parse::new(Pending(1,), input,),
   }
 }
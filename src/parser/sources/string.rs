@@ -0,0 +1,302 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+use core::str::Chars;
+#[cfg(feature = "alloc",)]
+use alloc::{borrow::Cow, string::String,};
+
+/// The error produced by an invalid escape sequence.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum EscapeError {
+  /// The escaped character has no meaning.
+  Unknown(char,),
+  /// A hexadecimal digit or brace was expected.
+  Hex,
+  /// A `\x` escape was outside of the ASCII range.
+  OutOfRange,
+  /// A `\u{...}` escape was not a Unicode scalar value.
+  Unicode,
+}
+
+/// The error produced by the string literal parsers.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum LiteralError {
+  /// The input did not begin with a string literal.
+  Quote,
+  /// An invalid escape sequence begins at the given byte offset of the input.
+  Escape(usize, EscapeError,),
+}
+
+/// A string literal accepted by `StringLit`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum Literal<'a,> {
+  /// A literal without escapes, borrowed from the input.
+  Borrowed(&'a str,),
+  /// A literal with escapes, borrowed from the input still escaped.
+  Escaped(&'a str,),
+}
+
+impl<'a,> Literal<'a,> {
+  /// The body of the literal as it appears in the input.
+  #[inline]
+  pub const fn as_str(&self,) -> &'a str {
+    match *self {
+      Literal::Borrowed(body,) | Literal::Escaped(body,) => body,
+    }
+  }
+  /// The unescaped characters of the literal.
+  #[inline]
+  pub fn chars(&self,) -> Unescape<'a,> {
+    Unescape { chars: self.as_str().chars(), escapes: matches!(self, Literal::Escaped(_,),), }
+  }
+  /// Unescapes the literal, only allocating if it contains escapes.
+  #[cfg(feature = "alloc",)]
+  pub fn unescape(self,) -> Cow<'a, str> {
+    match self {
+      Literal::Borrowed(body,) => Cow::Borrowed(body,),
+      Literal::Escaped(_,) => Cow::Owned(self.chars().collect::<String>(),),
+    }
+  }
+}
+
+/// An iterator over the unescaped characters of a `Literal`.
+#[derive(Clone, Debug,)]
+pub struct Unescape<'a,> {
+  /// The characters of the body.
+  chars: Chars<'a,>,
+  /// Whether backslashes begin escapes.
+  escapes: bool,
+}
+
+impl Iterator for Unescape<'_,> {
+  type Item = char;
+
+  fn next(&mut self,) -> Option<char> {
+    let c = self.chars.next()?;
+    if !self.escapes || c != '\\' { return Some(c,) }
+
+    let rest = self.chars.as_str();
+    match escape(rest,) {
+      Output((c, len,),) => { self.chars = rest[len..].chars(); Some(c,) },
+      //The body was validated when it was parsed.
+      _ => None,
+    }
+  }
+}
+
+/// A parser which accepts a quoted string literal.
+/// 
+/// Backslash escapes are processed by default. The escapes are `\n`, `\r`, `\t`, `\0`,
+/// `\\`, `\'`, `\"`, `\xNN` for ASCII characters and `\u{N...}` for any Unicode scalar
+/// value with up to six hexadecimal digits.
+/// 
+/// Raw strings of the form `r"..."` or `r#"..."#`, with any number of `#`, can be
+/// enabled. Their body is not escaped and ends at the first quote followed by as many `#`
+/// as opened the string.
+/// 
+/// If the input ends within the literal the parse is pending.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub struct StringLit {
+  /// The character which delimits the literal.
+  pub quote: char,
+  /// Whether backslash escapes are processed.
+  pub escapes: bool,
+  /// Whether raw strings are accepted.
+  pub raw: bool,
+}
+
+impl StringLit {
+  /// A double quoted literal with escapes.
+  pub const DOUBLE: Self = StringLit::new('"',);
+  /// A single quoted literal with escapes.
+  pub const SINGLE: Self = StringLit::new('\'',);
+
+  /// Constructs a new `StringLit` delimited by `quote` with escapes.
+  #[inline]
+  pub const fn new(quote: char,) -> Self { StringLit { quote, escapes: true, raw: false, } }
+  /// Treats backslashes as ordinary characters.
+  #[inline]
+  pub const fn no_escapes(mut self,) -> Self {
+    self.escapes = false;
+    self
+  }
+  /// Accepts raw strings.
+  #[inline]
+  pub const fn raw(mut self,) -> Self {
+    self.raw = true;
+    self
+  }
+  /// Finds the literal at the front of `input`, returning it and its total length.
+  fn literal<'a,>(&self, input: &'a str,) -> PResult<(Literal<'a,>, usize,), LiteralError,> {
+    if self.raw && input.starts_with('r',) { return self.raw_literal(input,) }
+
+    match input.chars().next() {
+      Some(c,) if c == self.quote => {},
+      Some(_,) => return Failed(LiteralError::Quote,),
+      None => return Pending(1,),
+    }
+
+    let start = self.quote.len_utf8();
+    let (mut index, mut escaped,) = (start, false,);
+    while let Some(c,) = input[index..].chars().next() {
+      if c == self.quote {
+        let body = &input[start..index];
+        let literal = if escaped { Literal::Escaped(body,) } else { Literal::Borrowed(body,) };
+
+        return Output((literal, index + c.len_utf8(),),)
+      }
+      if self.escapes && c == '\\' {
+        escaped = true;
+        match escape(&input[index + 1..],) {
+          Output((_, len,),) => index += 1 + len,
+          //The closing quote must still follow the escape.
+          Pending(pending,) => return Pending(pending + 1,),
+          Failed(error,) => return Failed(LiteralError::Escape(index, error,),),
+        }
+      } else { index += c.len_utf8() }
+    }
+
+    Pending(1,)
+  }
+  /// Finds the raw literal at the front of `input`, returning it and its total length.
+  fn raw_literal<'a,>(&self, input: &'a str,) -> PResult<(Literal<'a,>, usize,), LiteralError,> {
+    let hashes = input[1..].bytes().take_while(|&b,| b == b'#',).count();
+    let start = 1 + hashes;
+    match input[start..].chars().next() {
+      Some(c,) if c == self.quote => {},
+      Some(_,) => return Failed(LiteralError::Quote,),
+      None => return Pending(1,),
+    }
+
+    let start = start + self.quote.len_utf8();
+    let body = &input[start..];
+    let mut from = 0;
+    while let Some(end,) = body[from..].find(self.quote,) {
+      let end = from + end;
+      let close = end + self.quote.len_utf8();
+      let closing = body[close..].bytes().take(hashes,).take_while(|&b,| b == b'#',).count();
+      if closing == hashes { return Output((Literal::Borrowed(&body[..end],), start + close + hashes,),) }
+      //The input ended partway through the closing hashes.
+      if close + closing == body.len() { return Pending(hashes - closing,) }
+
+      from = close;
+    }
+
+    Pending(1 + hashes,)
+  }
+}
+
+impl<'a,> FnOnce<(&'a str,),> for StringLit {
+  type Output = Parse<PResult<Literal<'a,>, LiteralError,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a str,),> for StringLit {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a str,),> for StringLit {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    match self.literal(input,) {
+      Output((literal, len,),) => Parse::new(Output(literal,), &input[len..],),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(error,) => Parse::new(Failed(error,), input,),
+    }
+  }
+}
+
+/// A parser which accepts a quoted string literal and unescapes it.
+/// 
+/// The literal is borrowed from the input unless it contains escapes.
+#[cfg(feature = "alloc",)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+#[repr(transparent,)]
+pub struct Unescaped(pub StringLit,);
+
+#[cfg(feature = "alloc",)]
+impl<'a,> FnOnce<(&'a str,),> for Unescaped {
+  type Output = Parse<PResult<Cow<'a, str>, LiteralError,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<'a,> FnMut<(&'a str,),> for Unescaped {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+#[cfg(feature = "alloc",)]
+impl<'a,> Fn<(&'a str,),> for Unescaped {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    self.0(input,).map(|literal,| literal.map(Literal::unescape,),)
+  }
+}
+
+/// Parses the escape sequence following a backslash, returning the character and the
+/// length of the sequence.
+fn escape(input: &str,) -> PResult<(char, usize,), EscapeError,> {
+  let c = match input.chars().next() {
+    Some(c,) => c,
+    None => return Pending(1,),
+  };
+  let c = match c {
+    'n' => '\n',
+    'r' => '\r',
+    't' => '\t',
+    '0' => '\0',
+    '\\' | '\'' | '"' => c,
+    'x' => return match input.as_bytes().get(1..3,) {
+      Some(&[hi, lo,],) => match (hex(hi,), hex(lo,),) {
+        (Some(hi,), Some(lo,),) if hi < 8 => Output(((hi << 4 | lo) as u8 as char, 3,),),
+        (Some(_,), Some(_,),) => Failed(EscapeError::OutOfRange,),
+        _ => Failed(EscapeError::Hex,),
+      },
+      _ => match &input.as_bytes()[1..] {
+        digits if digits.iter().all(|&b,| hex(b,).is_some(),) => Pending(3 - input.len(),),
+        _ => Failed(EscapeError::Hex,),
+      },
+    },
+    'u' => return unicode(input,),
+    _ => return Failed(EscapeError::Unknown(c,),),
+  };
+
+  Output((c, 1,),)
+}
+
+/// Parses a `u{N...}` escape sequence.
+fn unicode(input: &str,) -> PResult<(char, usize,), EscapeError,> {
+  let bytes = input.as_bytes();
+  match bytes.get(1,) {
+    Some(b'{',) => {},
+    Some(_,) => return Failed(EscapeError::Hex,),
+    None => return Pending(1,),
+  }
+
+  let mut value = 0u32;
+  for (index, &b,) in bytes.iter().enumerate().skip(2,) {
+    if b == b'}' && index > 2 {
+      return match char::from_u32(value,) {
+        Some(c,) => Output((c, index + 1,),),
+        None => Failed(EscapeError::Unicode,),
+      }
+    }
+
+    match hex(b,) {
+      Some(digit,) if index < 8 => value = value << 4 | digit,
+      _ => return Failed(EscapeError::Hex,),
+    }
+  }
+
+  Pending(1,)
+}
+
+/// The value of a hexadecimal digit.
+#[inline]
+fn hex(b: u8,) -> Option<u32> { (b as char).to_digit(16,) }
@@ -1,7 +1,10 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
 use crate::*;
+#[cfg(not(feature = "nightly",),)]
+use crate::stable::StableParse;
+use super::case_fold::fold;
 
 /// A parser which accepts a specific sequence of tokens.
 /// 
@@ -26,36 +29,270 @@ impl<T,> Tag<T,> {
   }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, T, I,> FnOnce<(&'a [I],),> for Tag<T,>
   where T: AsRef<[I]>,
     I: PartialEq, {
   type Output = Parse<PResult<&'a [I], &'a [I],>, &'a [I],>;
 
   #[inline]
-  extern "rust-call" fn call_once(self, (input,): (&'a [I],),) -> Self::Output { (&self)(input,) }
+  extern "rust-call" fn call_once(self, (input,): (&'a [I],),) -> Self::Output { tag(self.tag.as_ref(), input, PartialEq::eq,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, T, I,> FnMut<(&'a [I],),> for Tag<T,>
   where T: AsRef<[I]>,
     I: PartialEq, {
   #[inline]
-  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output { (&*self)(input,) }
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output { tag(self.tag.as_ref(), input, PartialEq::eq,) }
 }
 
+#[cfg(feature = "nightly",)]
 impl<'a, T, I,> Fn<(&'a [I],),> for Tag<T,>
   where T: AsRef<[I]>,
     I: PartialEq, {
-  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output {
-    let tag = self.tag.as_ref();
-    let matched = tag.iter().zip(input,)
-      .take_while(|(a, b,),| a == b,)
-      .count();
-
-    if matched == tag.len() { Parse::from(input.split_at(matched,),).map(Output,) }
-    else { Parse::new(
-      if matched == input.len() { Pending(tag.len() - matched,) }
-      else { Failed(&input[..matched],) },
-      input,
-    ) }
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output { tag(self.tag.as_ref(), input, PartialEq::eq,) }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<'a, T, I,> StableParse<&'a [I],> for Tag<T,>
+  where T: AsRef<[I]>,
+    I: PartialEq, {
+  type Value = PResult<&'a [I], &'a [I],>;
+
+  #[inline]
+  fn parse(&self, input: &'a [I],) -> Parse<Self::Value, &'a [I],> { tag(self.tag.as_ref(), input, PartialEq::eq,) }
+}
+
+/// A parser which accepts a specific sequence of tokens ignoring case.
+/// 
+/// Bytes are compared using ASCII case folding and characters are compared using Unicode
+/// simple case folding.
+/// 
+/// If an unexpected token occurs the matched prefix is returned as the error.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[repr(transparent,)]
+pub struct TagNoCase<T,> {
+  /// The expected sequence of tokens.
+  pub tag: T,
+}
+
+impl<T,> TagNoCase<T,> {
+  /// Constructs a new `TagNoCase` with `tag`.
+  #[inline]
+  pub const fn new(tag: T,) -> Self { Self { tag, } }
+  /// Returns the inner value.
+  #[inline]
+  pub const fn into_inner(self,) -> T {
+    use core::mem::MaybeUninit;
+
+    unsafe { core::ptr::read(MaybeUninit::new(self,).as_ptr() as *const T,) }
+  }
+}
+
+#[cfg(feature = "nightly",)]
+impl<'a, T,> FnOnce<(&'a [u8],),> for TagNoCase<T,>
+  where T: AsRef<[u8]>, {
+  type Output = Parse<PResult<&'a [u8], &'a [u8],>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { tag(self.tag.as_ref(), input, u8::eq_ignore_ascii_case,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<'a, T,> FnMut<(&'a [u8],),> for TagNoCase<T,>
+  where T: AsRef<[u8]>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { tag(self.tag.as_ref(), input, u8::eq_ignore_ascii_case,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<'a, T,> Fn<(&'a [u8],),> for TagNoCase<T,>
+  where T: AsRef<[u8]>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output { tag(self.tag.as_ref(), input, u8::eq_ignore_ascii_case,) }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<'a, T,> StableParse<&'a [u8],> for TagNoCase<T,>
+  where T: AsRef<[u8]>, {
+  type Value = PResult<&'a [u8], &'a [u8],>;
+
+  #[inline]
+  fn parse(&self, input: &'a [u8],) -> Parse<Self::Value, &'a [u8],> { tag(self.tag.as_ref(), input, u8::eq_ignore_ascii_case,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<'a, T,> FnOnce<(&'a str,),> for TagNoCase<T,>
+  where T: AsRef<str>, {
+  type Output = Parse<PResult<&'a str, &'a str,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { tag_no_case(self.tag.as_ref(), input,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<'a, T,> FnMut<(&'a str,),> for TagNoCase<T,>
+  where T: AsRef<str>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { tag_no_case(self.tag.as_ref(), input,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<'a, T,> Fn<(&'a str,),> for TagNoCase<T,>
+  where T: AsRef<str>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output { tag_no_case(self.tag.as_ref(), input,) }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<'a, T,> StableParse<&'a str,> for TagNoCase<T,>
+  where T: AsRef<str>, {
+  type Value = PResult<&'a str, &'a str,>;
+
+  #[inline]
+  fn parse(&self, input: &'a str,) -> Parse<Self::Value, &'a str,> { tag_no_case(self.tag.as_ref(), input,) }
+}
+
+/// A parser which accepts a specific sequence of characters ignoring case after
+/// normalising both the input and the tag to NFC.
+/// 
+/// A match which ends at the end of the input is pending as a following combining
+/// character could change its normal form.
+/// 
+/// If an unexpected character occurs the matched prefix is returned as the error.
+#[cfg(feature = "nfc",)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[repr(transparent,)]
+pub struct TagNoCaseNfc<T,> {
+  /// The expected sequence of characters.
+  pub tag: T,
+}
+
+#[cfg(feature = "nfc",)]
+impl<T,> TagNoCaseNfc<T,> {
+  /// Constructs a new `TagNoCaseNfc` with `tag`.
+  #[inline]
+  pub const fn new(tag: T,) -> Self { Self { tag, } }
+  /// Returns the inner value.
+  #[inline]
+  pub const fn into_inner(self,) -> T {
+    use core::mem::MaybeUninit;
+
+    unsafe { core::ptr::read(MaybeUninit::new(self,).as_ptr() as *const T,) }
   }
 }
+
+#[cfg(all(feature = "nfc", feature = "nightly",),)]
+impl<'a, T,> FnOnce<(&'a str,),> for TagNoCaseNfc<T,>
+  where T: AsRef<str>, {
+  type Output = Parse<PResult<&'a str, &'a str,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { tag_no_case_nfc(self.tag.as_ref(), input,) }
+}
+
+#[cfg(all(feature = "nfc", feature = "nightly",),)]
+impl<'a, T,> FnMut<(&'a str,),> for TagNoCaseNfc<T,>
+  where T: AsRef<str>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { tag_no_case_nfc(self.tag.as_ref(), input,) }
+}
+
+#[cfg(all(feature = "nfc", feature = "nightly",),)]
+impl<'a, T,> Fn<(&'a str,),> for TagNoCaseNfc<T,>
+  where T: AsRef<str>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output { tag_no_case_nfc(self.tag.as_ref(), input,) }
+}
+
+#[cfg(all(feature = "nfc", not(feature = "nightly",),),)]
+impl<'a, T,> StableParse<&'a str,> for TagNoCaseNfc<T,>
+  where T: AsRef<str>, {
+  type Value = PResult<&'a str, &'a str,>;
+
+  #[inline]
+  fn parse(&self, input: &'a str,) -> Parse<Self::Value, &'a str,> { tag_no_case_nfc(self.tag.as_ref(), input,) }
+}
+
+/// Accepts `tag` from the front of `input` comparing tokens with `eq`.
+fn tag<'a, I,>(tag: &[I], input: &'a [I], eq: impl Fn(&I, &I,) -> bool,) -> Parse<PResult<&'a [I], &'a [I],>, &'a [I],> {
+  let matched = tag.iter().zip(input,)
+    .take_while(|(a, b,),| eq(a, b,),)
+    .count();
+
+  if matched == tag.len() { Parse::from(input.split_at(matched,),).map(Output,) }
+  else { Parse::new(
+    if matched == input.len() { Pending(tag.len() - matched,) }
+    else { Failed(&input[..matched],) },
+    input,
+  ) }
+}
+
+/// Accepts `tag` from the front of `input` ignoring case.
+fn tag_no_case<'a,>(tag: &str, input: &'a str,) -> Parse<PResult<&'a str, &'a str,>, &'a str,> {
+  let mut tag = tag.chars();
+  let mut chars = input.chars();
+  let mut matched = 0;
+  while let Some(t) = tag.next() {
+    match chars.next() {
+      Some(c) if eq_no_case(c, t,) => matched += c.len_utf8(),
+      Some(_) => return Parse::new(Failed(&input[..matched],), input,),
+      //Every remaining character needs at least one more byte.
+      None => return Parse::new(Pending(1 + tag.count(),), input,),
+    }
+  }
+
+  Parse::from(input.split_at(matched,),).map(Output,)
+}
+
+/// Accepts `tag` from the front of `input` ignoring case after normalising both to NFC.
+#[cfg(feature = "nfc",)]
+fn tag_no_case_nfc<'a,>(tag: &str, input: &'a str,) -> Parse<PResult<&'a str, &'a str,>, &'a str,> {
+  use unicode_normalization::UnicodeNormalization;
+
+  //The tag is normalised once and each segment of the input is normalised once as it is
+  //compared, segments are split so that no composition is cut in half.
+  let mut tag = tag.nfc().peekable();
+  let mut matched = 0;
+  loop {
+    let end = segment_end(input, matched,);
+    if !input[matched..end].nfc().all(|c,| tag.next().is_some_and(|t,| eq_no_case(c, t,),),) {
+      return Parse::new(Failed(&input[..matched],), input,)
+    }
+    if end == input.len() { return Parse::new(Pending(1,), input,) }
+
+    matched = end;
+    if tag.peek().is_none() { return Parse::from(input.split_at(end,),).map(Output,) }
+  }
+}
+
+/// Finds the end of the segment of `input` starting at `start`.
+/// 
+/// A segment ends before a starter which cannot compose with the segment.
+#[cfg(feature = "nfc",)]
+fn segment_end(input: &str, start: usize,) -> usize {
+  use unicode_normalization::char::{canonical_combining_class, compose,};
+
+  let mut chars = input[start..].char_indices();
+  let mut last = match chars.next() {
+    Some((_, c,),) => Some(c,),
+    None => return start,
+  };
+  for (index, c,) in chars {
+    //A non-starter blocks the following starters from composing with the segment.
+    if canonical_combining_class(c,) != 0 { last = None; continue }
+
+    match last.and_then(|last,| compose(last, c,),) {
+      Some(composed,) => last = Some(composed,),
+      None => return start + index,
+    }
+  }
+
+  input.len()
+}
+
+/// Compares characters using Unicode simple case folding.
+#[inline]
+fn eq_no_case(a: char, b: char,) -> bool { a == b || fold(a,) == fold(b,) }
@@ -0,0 +1,261 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+use memchr::{memchr, memchr2, memchr3, memmem,};
+
+/// A parser which accepts tokens up to the first occurrence of a delimiter.
+/// 
+/// The delimiter is not consumed. If the delimiter does not occur the minimum number of
+/// tokens which could complete it is returned as pending.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[repr(transparent,)]
+pub struct TakeUntil<T,> {
+  /// The delimiter to search for.
+  pub tag: T,
+}
+
+impl<T,> TakeUntil<T,> {
+  /// Constructs a new `TakeUntil` with `tag`.
+  #[inline]
+  pub const fn new(tag: T,) -> Self { TakeUntil { tag, } }
+  /// Returns the inner value.
+  #[inline]
+  pub const fn into_inner(self,) -> T {
+    use core::mem::MaybeUninit;
+
+    unsafe { core::ptr::read(MaybeUninit::new(self,).as_ptr() as *const T,) }
+  }
+}
+
+impl<'a, T,> FnOnce<(&'a [u8],),> for TakeUntil<T,>
+  where T: AsRef<[u8]>, {
+  type Output = Parse<PResult<&'a [u8], !,>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a, T,> FnMut<(&'a [u8],),> for TakeUntil<T,>
+  where T: AsRef<[u8]>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a, T,> Fn<(&'a [u8],),> for TakeUntil<T,>
+  where T: AsRef<[u8]>, {
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output {
+    let tag = self.tag.as_ref();
+
+    match memmem::find(input, tag,) {
+      Some(index) => Parse::from(input.split_at(index,),).map(Output,),
+      None => Parse::new(Pending(pending(input, tag,),), input,),
+    }
+  }
+}
+
+impl<'a, T,> FnOnce<(&'a str,),> for TakeUntil<T,>
+  where T: AsRef<str>, {
+  type Output = Parse<PResult<&'a str, !,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a, T,> FnMut<(&'a str,),> for TakeUntil<T,>
+  where T: AsRef<str>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a, T,> Fn<(&'a str,),> for TakeUntil<T,>
+  where T: AsRef<str>, {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    let tag = self.tag.as_ref();
+
+    match memmem::find(input.as_bytes(), tag.as_bytes(),) {
+      Some(index) => Parse::from(input.split_at(index,),).map(Output,),
+      None => Parse::new(Pending(pending(input.as_bytes(), tag.as_bytes(),),), input,),
+    }
+  }
+}
+
+/// A parser which accepts tokens until one satisfies a predicate.
+/// 
+/// The token satisfying the predicate is not consumed.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[repr(transparent,)]
+pub struct TakeTill<F,> {
+  /// The predicate to apply.
+  pub pred: F,
+}
+
+impl<F,> TakeTill<F,> {
+  /// Constructs a new `TakeTill` with `pred`.
+  #[inline]
+  pub const fn new(pred: F,) -> Self { TakeTill { pred, } }
+  /// Returns the inner value.
+  #[inline]
+  pub const fn into_inner(self,) -> F {
+    use core::mem::MaybeUninit;
+
+    unsafe { core::ptr::read(MaybeUninit::new(self,).as_ptr() as *const F,) }
+  }
+}
+
+impl<'a, F, I,> FnOnce<(&'a [I],),> for TakeTill<F,>
+  where F: FnMut(&'a I,) -> bool, {
+  type Output = Parse<PResult<&'a [I], !,>, &'a [I],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(mut self, (input,): (&'a [I],),) -> Self::Output { (&mut self)(input,) }
+}
+
+impl<'a, F, I,> FnMut<(&'a [I],),> for TakeTill<F,>
+  where F: FnMut(&'a I,) -> bool, {
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [I],),) -> Self::Output {
+    match input.iter().position(&mut self.pred,) {
+      Some(index) => Parse::from(input.split_at(index,),).map(Output,),
+      None => Parse::new(Pending(1,), input,),
+    }
+  }
+}
+
+impl<'a, F, I,> Fn<(&'a [I],),> for TakeTill<F,>
+  where F: Fn(&'a I,) -> bool, {
+  extern "rust-call" fn call(&self, (input,): (&'a [I],),) -> Self::Output {
+    match input.iter().position(&self.pred,) {
+      Some(index) => Parse::from(input.split_at(index,),).map(Output,),
+      None => Parse::new(Pending(1,), input,),
+    }
+  }
+}
+
+impl<'a, F,> FnOnce<(&'a str,),> for TakeTill<F,>
+  where F: FnMut(char,) -> bool, {
+  type Output = Parse<PResult<&'a str, !,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(mut self, (input,): (&'a str,),) -> Self::Output { (&mut self)(input,) }
+}
+
+impl<'a, F,> FnMut<(&'a str,),> for TakeTill<F,>
+  where F: FnMut(char,) -> bool, {
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output {
+    match input.find(&mut self.pred,) {
+      Some(index) => Parse::from(input.split_at(index,),).map(Output,),
+      None => Parse::new(Pending(1,), input,),
+    }
+  }
+}
+
+impl<'a, F,> Fn<(&'a str,),> for TakeTill<F,>
+  where F: Fn(char,) -> bool, {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    match input.find(&self.pred,) {
+      Some(index) => Parse::from(input.split_at(index,),).map(Output,),
+      None => Parse::new(Pending(1,), input,),
+    }
+  }
+}
+
+/// A parser which accepts tokens up to the first token from a set of delimiters.
+/// 
+/// The delimiter is not consumed.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[repr(transparent,)]
+pub struct TakeUntilAny<T,> {
+  /// The delimiting tokens.
+  pub set: T,
+}
+
+impl<T,> TakeUntilAny<T,> {
+  /// Constructs a new `TakeUntilAny` with `set`.
+  #[inline]
+  pub const fn new(set: T,) -> Self { TakeUntilAny { set, } }
+  /// Returns the inner value.
+  #[inline]
+  pub const fn into_inner(self,) -> T {
+    use core::mem::MaybeUninit;
+
+    unsafe { core::ptr::read(MaybeUninit::new(self,).as_ptr() as *const T,) }
+  }
+}
+
+impl<'a, T,> FnOnce<(&'a [u8],),> for TakeUntilAny<T,>
+  where T: AsRef<[u8]>, {
+  type Output = Parse<PResult<&'a [u8], !,>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a, T,> FnMut<(&'a [u8],),> for TakeUntilAny<T,>
+  where T: AsRef<[u8]>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a, T,> Fn<(&'a [u8],),> for TakeUntilAny<T,>
+  where T: AsRef<[u8]>, {
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output {
+    match find_any(input, self.set.as_ref(),) {
+      Some(index) => Parse::from(input.split_at(index,),).map(Output,),
+      None => Parse::new(Pending(1,), input,),
+    }
+  }
+}
+
+impl<'a, T,> FnOnce<(&'a str,),> for TakeUntilAny<T,>
+  where T: AsRef<str>, {
+  type Output = Parse<PResult<&'a str, !,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a, T,> FnMut<(&'a str,),> for TakeUntilAny<T,>
+  where T: AsRef<str>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a, T,> Fn<(&'a str,),> for TakeUntilAny<T,>
+  where T: AsRef<str>, {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    let set = self.set.as_ref();
+    //ASCII bytes never occur within a multibyte character so the byte search is exact.
+    let index = if set.is_ascii() { find_any(input.as_bytes(), set.as_bytes(),) }
+      else { input.find(|c,| set.contains(c,),) };
+
+    match index {
+      Some(index) => Parse::from(input.split_at(index,),).map(Output,),
+      None => Parse::new(Pending(1,), input,),
+    }
+  }
+}
+
+/// Finds the first byte of `input` which occurs in `set`.
+#[inline]
+fn find_any(input: &[u8], set: &[u8],) -> Option<usize> {
+  match *set {
+    [] => None,
+    [a,] => memchr(a, input,),
+    [a, b,] => memchr2(a, b, input,),
+    [a, b, c,] => memchr3(a, b, c, input,),
+    _ => input.iter().position(|b,| set.contains(b,),),
+  }
+}
+
+/// The minimum number of bytes which must be appended to `input` for `tag` to occur.
+/// 
+/// `input` is assumed to not contain `tag`.
+fn pending(input: &[u8], tag: &[u8],) -> usize {
+  //The longest suffix of the input which could begin the tag.
+  let longest = tag.len().saturating_sub(1,).min(input.len(),);
+  let matched = (1..=longest).rev()
+    .find(|&len,| input.ends_with(&tag[..len],),)
+    .unwrap_or(0,);
+
+  tag.len() - matched
+}
@@ -0,0 +1,48 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{*, parser::lexer::{Spanned, TokenKind,},};
+
+/// A parser which accepts the next token if it is of a kind.
+/// 
+/// The token and its span are output, if the token is of another kind it is returned as
+/// the error so its span can be reported.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct OfKind<K,> {
+  /// The expected kind.
+  pub kind: K,
+}
+
+impl<K,> OfKind<K,> {
+  /// Constructs a new `OfKind` accepting tokens of `kind`.
+  #[inline]
+  pub const fn new(kind: K,) -> Self { OfKind { kind, } }
+}
+
+impl<'a, K, T,> FnOnce<(&'a [Spanned<T,>],),> for OfKind<K,>
+  where T: TokenKind<Kind = K>,
+    K: PartialEq, {
+  type Output = Parse<PResult<&'a Spanned<T,>, &'a Spanned<T,>,>, &'a [Spanned<T,>],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [Spanned<T,>],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a, K, T,> FnMut<(&'a [Spanned<T,>],),> for OfKind<K,>
+  where T: TokenKind<Kind = K>,
+    K: PartialEq, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [Spanned<T,>],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a, K, T,> Fn<(&'a [Spanned<T,>],),> for OfKind<K,>
+  where T: TokenKind<Kind = K>,
+    K: PartialEq, {
+  extern "rust-call" fn call(&self, (input,): (&'a [Spanned<T,>],),) -> Self::Output {
+    match input.split_first() {
+      Some((token, rest,),) if token.value.kind() == self.kind => Parse::new(Output(token,), rest,),
+      Some((token, _,),) => Parse::new(Failed(token,), input,),
+      None => Parse::new(Pending(1,), input,),
+    }
+  }
+}
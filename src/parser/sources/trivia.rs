@@ -0,0 +1,164 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+use memchr::{memchr, memmem,};
+
+/// A parser which skips whitespace and comments.
+/// 
+/// Whitespace is always skipped, line and block comments are skipped if their markers
+/// are set. A line comment runs up to and including the next newline, a block comment
+/// runs up to the closing marker and may optionally contain nested block comments.
+/// 
+/// On `&str` inputs any Unicode whitespace is skipped, on `&[u8]` inputs only ASCII
+/// whitespace is skipped. If the input ends within the trivia, or within what could be
+/// the start of a comment, the parse is pending.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Trivia {
+  /// The marker which starts a line comment.
+  pub line: Option<&'static str>,
+  /// The markers which open and close a block comment.
+  pub block: Option<(&'static str, &'static str,)>,
+  /// Whether block comments can be nested.
+  pub nested: bool,
+}
+
+impl Trivia {
+  /// Trivia which is only whitespace.
+  pub const WHITESPACE: Self = Trivia { line: None, block: None, nested: false, };
+
+  /// Skips line comments starting with `marker`.
+  #[inline]
+  pub const fn line_comment(mut self, marker: &'static str,) -> Self {
+    self.line = Some(marker,);
+    self
+  }
+  /// Skips block comments between `open` and `close`.
+  #[inline]
+  pub const fn block_comment(mut self, open: &'static str, close: &'static str,) -> Self {
+    self.block = Some((open, close,),);
+    self
+  }
+  /// Allows block comments to be nested.
+  #[inline]
+  pub const fn nested(mut self,) -> Self {
+    self.nested = true;
+    self
+  }
+  /// Finds the length of the trivia at the front of `input`.
+  /// 
+  /// `whitespace` returns the length of the whitespace starting at an index.
+  fn skip<W,>(&self, input: &[u8], whitespace: W,) -> PResult<usize, !,>
+    where W: Fn(usize,) -> usize, {
+    let mut index = 0;
+    loop {
+      index += whitespace(index,);
+
+      let rest = &input[index..];
+      if rest.is_empty() { return Pending(1,) }
+      if let Some(line,) = self.line {
+        let line = line.as_bytes();
+        if rest.starts_with(line,) {
+          match memchr(b'\n', &rest[line.len()..],) {
+            Some(end) => { index += line.len() + end + 1; continue },
+            None => return Pending(1,),
+          }
+        }
+        if line.starts_with(rest,) { return Pending(line.len() - rest.len(),) }
+      }
+      if let Some((open, close,),) = self.block {
+        let (open, close,) = (open.as_bytes(), close.as_bytes(),);
+        if rest.starts_with(open,) {
+          match self.block_len(&rest[open.len()..], open, close,) {
+            Output(len,) => { index += open.len() + len; continue },
+            Pending(pending,) => return Pending(pending,),
+            Failed(never,) => never,
+          }
+        }
+        if open.starts_with(rest,) { return Pending(open.len() - rest.len(),) }
+      }
+
+      return Output(index,)
+    }
+  }
+  /// Finds the length of the body and closing marker of a block comment.
+  fn block_len(&self, input: &[u8], open: &[u8], close: &[u8],) -> PResult<usize, !,> {
+    let (mut index, mut depth,) = (0, 1,);
+    while depth > 0 {
+      let rest = &input[index..];
+      let end = match memmem::find(rest, close,) {
+        Some(end) => end,
+        None => {
+          //The longest suffix of the input which could begin the closing marker.
+          let matched = (1..close.len().min(rest.len() + 1,)).rev()
+            .find(|&len,| rest.ends_with(&close[..len],),)
+            .unwrap_or(0,);
+          if self.nested { depth += memmem::find_iter(rest, open,).count() }
+
+          return Pending(depth * close.len() - matched,)
+        },
+      };
+      match memmem::find(&rest[..end], open,) {
+        Some(start) if self.nested => { index += start + open.len(); depth += 1 },
+        _ => { index += end + close.len(); depth -= 1 },
+      }
+    }
+
+    Output(index,)
+  }
+}
+
+impl<'a,> FnOnce<(&'a [u8],),> for Trivia {
+  type Output = Parse<PResult<&'a [u8], !,>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a [u8],),> for Trivia {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a [u8],),> for Trivia {
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output {
+    let whitespace = |index: usize,| input[index..].iter()
+      .take_while(|b,| b.is_ascii_whitespace(),)
+      .count();
+
+    match self.skip(input, whitespace,) {
+      Output(len,) => Parse::from(input.split_at(len,),).map(Output,),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(never,) => never,
+    }
+  }
+}
+
+impl<'a,> FnOnce<(&'a str,),> for Trivia {
+  type Output = Parse<PResult<&'a str, !,>, &'a str,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a str,),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a str,),> for Trivia {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a str,),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a str,),> for Trivia {
+  extern "rust-call" fn call(&self, (input,): (&'a str,),) -> Self::Output {
+    //Trivia only ends on a character boundary so every index passed here is one.
+    let whitespace = |index: usize,| {
+      let rest = &input[index..];
+
+      rest.find(|c: char,| !c.is_whitespace(),).unwrap_or(rest.len(),)
+    };
+
+    match self.skip(input.as_bytes(), whitespace,) {
+      Output(len,) => Parse::from(input.split_at(len,),).map(Output,),
+      Pending(pending,) => Parse::new(Pending(pending,), input,),
+      Failed(never,) => never,
+    }
+  }
+}
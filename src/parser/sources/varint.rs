@@ -0,0 +1,201 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+
+/// A parser which accepts an unsigned LEB128 encoded integer.
+/// 
+/// If the encoded value overflows a `u64` the bytes of the encoding are returned as the
+/// error.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct ULeb128;
+
+/// A parser which accepts a protobuf style varint.
+/// 
+/// Protobuf varints are unsigned LEB128 encoded integers.
+pub type VarInt = ULeb128;
+
+impl<'a,> FnOnce<(&'a [u8],),> for ULeb128 {
+  type Output = Parse<PResult<u64, &'a [u8],>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a [u8],),> for ULeb128 {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a [u8],),> for ULeb128 {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output { uleb128(input,) }
+}
+
+/// A parser which accepts a signed LEB128 encoded integer.
+/// 
+/// If the encoded value overflows an `i64` the bytes of the encoding are returned as the
+/// error.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct SLeb128;
+
+impl<'a,> FnOnce<(&'a [u8],),> for SLeb128 {
+  type Output = Parse<PResult<i64, &'a [u8],>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a [u8],),> for SLeb128 {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a [u8],),> for SLeb128 {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output { sleb128(input,) }
+}
+
+/// A parser which accepts a protobuf style zigzag encoded varint.
+/// 
+/// If the encoded value overflows a `u64` the bytes of the encoding are returned as the
+/// error.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct ZigZag;
+
+impl<'a,> FnOnce<(&'a [u8],),> for ZigZag {
+  type Output = Parse<PResult<i64, &'a [u8],>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a [u8],),> for ZigZag {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a [u8],),> for ZigZag {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output {
+    uleb128(input,).map(|value,| value.map(zigzag,),)
+  }
+}
+
+/// A parser which accepts a big endian varint as used by SQLite.
+/// 
+/// The first eight bytes hold seven bits each with the high bit marking a continuation,
+/// a ninth byte contributes all eight of its bits so the encoding cannot overflow.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct BeVarInt;
+
+impl<'a,> FnOnce<(&'a [u8],),> for BeVarInt {
+  type Output = Parse<PResult<u64, !,>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a [u8],),> for BeVarInt {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a [u8],),> for BeVarInt {
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output {
+    let mut value = 0u64;
+    for (i, &byte,) in input.iter().enumerate() {
+      if i == 8 { return Parse::new(Output(value << 8 | byte as u64,), &input[9..],) }
+
+      value = value << 7 | (byte & 0x7f) as u64;
+      if byte & 0x80 == 0 { return Parse::new(Output(value,), &input[i + 1..],) }
+    }
+
+    Parse::new(Pending(1,), input,)
+  }
+}
+
+/// A parser which accepts a big endian varint as used by Git for pack offsets.
+/// 
+/// Each byte holds seven bits with the high bit marking a continuation and one is added to
+/// the value before each continuation so that every value has a single encoding.
+/// 
+/// If the encoded value overflows a `u64` the bytes of the encoding are returned as the
+/// error.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct OffsetVarInt;
+
+impl<'a,> FnOnce<(&'a [u8],),> for OffsetVarInt {
+  type Output = Parse<PResult<u64, &'a [u8],>, &'a [u8],>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (&'a [u8],),) -> Self::Output { (&self)(input,) }
+}
+
+impl<'a,> FnMut<(&'a [u8],),> for OffsetVarInt {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (&'a [u8],),) -> Self::Output { (&*self)(input,) }
+}
+
+impl<'a,> Fn<(&'a [u8],),> for OffsetVarInt {
+  extern "rust-call" fn call(&self, (input,): (&'a [u8],),) -> Self::Output {
+    let mut value = 0u64;
+    for (i, &byte,) in input.iter().enumerate() {
+      if i > 0 {
+        //The incremented value must leave room for the next seven bits.
+        match value.checked_add(1,).filter(|value,| value >> 57 == 0,) {
+          Some(next,) => value = next << 7,
+          None => return Parse::new(Failed(&input[..=i],), input,),
+        }
+      }
+
+      value |= (byte & 0x7f) as u64;
+      if byte & 0x80 == 0 { return Parse::new(Output(value,), &input[i + 1..],) }
+    }
+
+    Parse::new(Pending(1,), input,)
+  }
+}
+
+/// Decodes an unsigned LEB128 integer from the front of `input`.
+fn uleb128(input: &[u8],) -> Parse<PResult<u64, &[u8],>, &[u8],> {
+  let mut value = 0u64;
+  for (i, &byte,) in input.iter().enumerate() {
+    let shift = 7 * i as u32;
+    //The tenth byte may only contribute the top bit.
+    if shift == 63 && byte > 1 { return Parse::new(Failed(&input[..=i],), input,) }
+
+    value |= ((byte & 0x7f) as u64) << shift;
+    if byte & 0x80 == 0 { return Parse::new(Output(value,), &input[i + 1..],) }
+  }
+
+  Parse::new(Pending(1,), input,)
+}
+
+/// Decodes a signed LEB128 integer from the front of `input`.
+fn sleb128(input: &[u8],) -> Parse<PResult<i64, &[u8],>, &[u8],> {
+  let mut value = 0i64;
+  for (i, &byte,) in input.iter().enumerate() {
+    let shift = 7 * i as u32;
+    //The tenth byte may only contribute the sign bit and its extension.
+    if shift == 63 {
+      if byte != 0x00 && byte != 0x7f { return Parse::new(Failed(&input[..=i],), input,) }
+
+      value |= ((byte & 1) as i64) << 63;
+      return Parse::new(Output(value,), &input[i + 1..],)
+    }
+
+    value |= ((byte & 0x7f) as i64) << shift;
+    if byte & 0x80 == 0 {
+      if byte & 0x40 != 0 { value |= !0 << (shift + 7); }
+
+      return Parse::new(Output(value,), &input[i + 1..],)
+    }
+  }
+
+  Parse::new(Pending(1,), input,)
+}
+
+/// Decodes a zigzag encoded integer.
+#[inline]
+const fn zigzag(value: u64,) -> i64 { (value >> 1) as i64 ^ -((value & 1) as i64) }
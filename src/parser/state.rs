@@ -0,0 +1,212 @@
+//! Defines the [`Stateful`](self::Stateful) input adaptor for parsers which thread user
+//! state alongside their input.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+
+/// An input adaptor which carries user state alongside an input.
+/// 
+/// The state is part of the input so it is restored whenever a parser backtracks to an
+/// earlier input, such as when the first parser of an `or` fails. Backtracking clones the
+/// input so the state should be cheap to clone, a persistent map rather than a `HashMap`
+/// for example.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Stateful<I, S,> {
+  /// The remaining input.
+  pub input: I,
+  /// The user state.
+  pub state: S,
+}
+
+impl<I, S,> Stateful<I, S,> {
+  /// Constructs a new `Stateful` carrying `state` alongside `input`.
+  #[inline]
+  pub const fn new(input: I, state: S,) -> Self { Stateful { input, state, } }
+}
+
+/// A parser which applies a stateful parser to an input, starting from an initial state.
+/// 
+/// The final state is output alongside the value of the parser. Unless applied once the
+/// initial state is cloned each time the parser is applied.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct WithState<P, S,> {
+  /// The stateful parser.
+  pub parser: P,
+  /// The initial state.
+  pub state: S,
+}
+
+impl<P, S,> WithState<P, S,> {
+  /// Constructs a new `WithState` applying `parser` starting from `state`.
+  #[inline]
+  pub const fn new(parser: P, state: S,) -> Self { WithState { parser, state, } }
+}
+
+impl<P, S, I,> FnOnce<(I,),> for WithState<P, S,>
+  where P: ParserFnOnce<Stateful<I, S,>,>, {
+  type Output = Parse<(P::Value, S,), I,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output {
+    let Parse { value, unused, } = self.parser.parse_once(Stateful::new(input, self.state,),);
+    Parse::new((value, unused.state,), unused.input,)
+  }
+}
+
+impl<P, S, I,> FnMut<(I,),> for WithState<P, S,>
+  where P: ParserFnMut<Stateful<I, S,>,>,
+    S: Clone, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output {
+    let Parse { value, unused, } = self.parser.parse_mut(Stateful::new(input, self.state.clone(),),);
+    Parse::new((value, unused.state,), unused.input,)
+  }
+}
+
+impl<P, S, I,> Fn<(I,),> for WithState<P, S,>
+  where P: ParserFn<Stateful<I, S,>,>,
+    S: Clone, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output {
+    let Parse { value, unused, } = self.parser.parse(Stateful::new(input, self.state.clone(),),);
+    Parse::new((value, unused.state,), unused.input,)
+  }
+}
+
+/// A parser which applies a stateless parser to a stateful input.
+/// 
+/// The state is left unchanged.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct InState<P,>(pub P,);
+
+impl<P,> InState<P,> {
+  /// Constructs a new `InState` from `parser`.
+  #[inline]
+  pub const fn new(parser: P,) -> Self { InState(parser,) }
+  /// Returns the inner value.
+  #[inline]
+  pub const fn into_inner(self,) -> P {
+    use core::mem::MaybeUninit;
+
+    unsafe { core::ptr::read(MaybeUninit::new(self,).as_ptr() as *const Self as *const P,) }
+  }
+}
+
+impl<P, I, S,> FnOnce<(Stateful<I, S,>,),> for InState<P,>
+  where P: ParserFnOnce<I,>, {
+  type Output = Parse<P::Value, Stateful<I, S,>,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (Stateful { input, state, },): (Stateful<I, S,>,),) -> Self::Output {
+    let Parse { value, unused, } = self.0.parse_once(input,);
+    Parse::new(value, Stateful::new(unused, state,),)
+  }
+}
+
+impl<P, I, S,> FnMut<(Stateful<I, S,>,),> for InState<P,>
+  where P: ParserFnMut<I,>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (Stateful { input, state, },): (Stateful<I, S,>,),) -> Self::Output {
+    let Parse { value, unused, } = self.0.parse_mut(input,);
+    Parse::new(value, Stateful::new(unused, state,),)
+  }
+}
+
+impl<P, I, S,> Fn<(Stateful<I, S,>,),> for InState<P,>
+  where P: ParserFn<I,>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (Stateful { input, state, },): (Stateful<I, S,>,),) -> Self::Output {
+    let Parse { value, unused, } = self.0.parse(input,);
+    Parse::new(value, Stateful::new(unused, state,),)
+  }
+}
+
+/// A parser which outputs a value read from the state without consuming any input.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct GetState<F,>(pub F,);
+
+impl<F,> GetState<F,> {
+  /// Constructs a new `GetState` reading the state with `get`.
+  #[inline]
+  pub const fn new(get: F,) -> Self { GetState(get,) }
+}
+
+impl<F, T, I, S,> FnOnce<(Stateful<I, S,>,),> for GetState<F,>
+  where F: FnOnce(&S,) -> T, {
+  type Output = Parse<PResult<T, !,>, Stateful<I, S,>,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (Stateful<I, S,>,),) -> Self::Output {
+    Parse::new(Output((self.0)(&input.state,),), input,)
+  }
+}
+
+impl<F, T, I, S,> FnMut<(Stateful<I, S,>,),> for GetState<F,>
+  where F: FnMut(&S,) -> T, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (Stateful<I, S,>,),) -> Self::Output {
+    Parse::new(Output((self.0)(&input.state,),), input,)
+  }
+}
+
+impl<F, T, I, S,> Fn<(Stateful<I, S,>,),> for GetState<F,>
+  where F: Fn(&S,) -> T, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (Stateful<I, S,>,),) -> Self::Output {
+    Parse::new(Output((self.0)(&input.state,),), input,)
+  }
+}
+
+/// A parser which replaces the state without consuming any input.
+/// 
+/// The update reads the current state and outputs a value alongside the new state, if it
+/// does not produce an output the state is left unchanged.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct UpdateState<F,>(pub F,);
+
+impl<F,> UpdateState<F,> {
+  /// Constructs a new `UpdateState` replacing the state with `update`.
+  #[inline]
+  pub const fn new(update: F,) -> Self { UpdateState(update,) }
+}
+
+impl<F, T, E, I, S,> FnOnce<(Stateful<I, S,>,),> for UpdateState<F,>
+  where F: FnOnce(&S,) -> PResult<(T, S,), E,>, {
+  type Output = Parse<PResult<T, E,>, Stateful<I, S,>,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (Stateful<I, S,>,),) -> Self::Output {
+    update((self.0)(&input.state,), input,)
+  }
+}
+
+impl<F, T, E, I, S,> FnMut<(Stateful<I, S,>,),> for UpdateState<F,>
+  where F: FnMut(&S,) -> PResult<(T, S,), E,>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (Stateful<I, S,>,),) -> Self::Output {
+    update((self.0)(&input.state,), input,)
+  }
+}
+
+impl<F, T, E, I, S,> Fn<(Stateful<I, S,>,),> for UpdateState<F,>
+  where F: Fn(&S,) -> PResult<(T, S,), E,>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (Stateful<I, S,>,),) -> Self::Output {
+    update((self.0)(&input.state,), input,)
+  }
+}
+
+/// Replaces the state of `input` if the update produced an output.
+#[inline]
+fn update<T, E, I, S,>(value: PResult<(T, S,), E,>, input: Stateful<I, S,>,) -> Parse<PResult<T, E,>, Stateful<I, S,>,> {
+  match value {
+    Output((value, state,),) => Parse::new(Output(value,), Stateful::new(input.input, state,),),
+    Pending(pending,) => Parse::new(Pending(pending,), input,),
+    Failed(error,) => Parse::new(Failed(error,), input,),
+  }
+}
@@ -0,0 +1,112 @@
+//! Tracing of named rules as an indented call tree.
+//! 
+//! Tracing is enabled globally with [`set_enabled`](self::set_enabled). While it is
+//! enabled each [`Trace`](self::Trace) rule reports its entry offset when it is applied
+//! and its result and the length of input it consumed when it returns. Offsets are
+//! relative to the input of the outermost traced rule.
+//! 
+//! With the `log` feature the call tree is emitted with `log::trace!`, otherwise it is
+//! printed to stderr. The depth of the tree is global so tracing is intended for
+//! debugging a single thread.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{*, parser::{Remaining, Variant,},};
+use core::{
+  fmt,
+  sync::atomic::{AtomicBool, AtomicUsize, Ordering,},
+};
+
+/// Whether tracing is enabled.
+static ENABLED: AtomicBool = AtomicBool::new(false,);
+/// The depth of the call tree.
+static DEPTH: AtomicUsize = AtomicUsize::new(0,);
+/// The remaining length of the input of the outermost traced rule.
+static ROOT: AtomicUsize = AtomicUsize::new(0,);
+
+/// Enables or disables tracing.
+#[inline]
+pub fn set_enabled(enabled: bool,) { ENABLED.store(enabled, Ordering::Relaxed,) }
+/// Checks if tracing is enabled.
+#[inline]
+pub fn is_enabled() -> bool { ENABLED.load(Ordering::Relaxed,) }
+
+/// A parser which traces the application of the inner parser as a named rule.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Trace<P,> {
+  /// The name of the rule.
+  pub name: &'static str,
+  /// The parser for the rule.
+  pub parser: P,
+}
+
+impl<P,> Trace<P,> {
+  /// Constructs a new `Trace` of `parser` named `name`.
+  #[inline]
+  pub const fn new(name: &'static str, parser: P,) -> Self { Trace { name, parser, } }
+}
+
+impl<P, I,> FnOnce<(I,),> for Trace<P,>
+  where P: ParserFnOnce<I,>,
+    P::Value: Variant,
+    I: Remaining, {
+  type Output = Parse<P::Value, I,>;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output {
+    let parser = self.parser;
+    trace(self.name, input, |input,| parser.parse_once(input,),)
+  }
+}
+
+impl<P, I,> FnMut<(I,),> for Trace<P,>
+  where P: ParserFnMut<I,>,
+    P::Value: Variant,
+    I: Remaining, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output {
+    let parser = &mut self.parser;
+    trace(self.name, input, |input,| parser.parse_mut(input,),)
+  }
+}
+
+impl<P, I,> Fn<(I,),> for Trace<P,>
+  where P: ParserFn<I,>,
+    P::Value: Variant,
+    I: Remaining, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output {
+    trace(self.name, input, |input,| self.parser.parse(input,),)
+  }
+}
+
+/// Applies `parser` to `input`, tracing it as the rule `name` if tracing is enabled.
+fn trace<V, I, F,>(name: &str, input: I, parser: F,) -> Parse<V, I,>
+  where F: FnOnce(I,) -> Parse<V, I,>,
+    V: Variant,
+    I: Remaining, {
+  if !is_enabled() { return parser(input,) }
+
+  let remaining = input.remaining();
+  let depth = DEPTH.fetch_add(1, Ordering::Relaxed,);
+  if depth == 0 { ROOT.store(remaining, Ordering::Relaxed,) }
+  let offset = ROOT.load(Ordering::Relaxed,).saturating_sub(remaining,);
+  emit(depth, format_args!("{} @{}", name, offset,),);
+
+  let parse = parser(input,);
+  DEPTH.fetch_sub(1, Ordering::Relaxed,);
+  let consumed = remaining.saturating_sub(parse.unused.remaining(),);
+  emit(depth, format_args!("{} -> {} ({} consumed)", name, parse.value.variant(), consumed,),);
+
+  parse
+}
+
+/// Emits a line of the call tree at `depth`.
+#[cfg(feature = "log",)]
+#[inline]
+fn emit(depth: usize, line: fmt::Arguments,) { log::trace!("{:indent$}{}", "", line, indent = 2 * depth,) }
+/// Emits a line of the call tree at `depth`.
+#[cfg(not(feature = "log",),)]
+#[inline]
+fn emit(depth: usize, line: fmt::Arguments,) { std::eprintln!("{:indent$}{}", "", line, indent = 2 * depth,) }
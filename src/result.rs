@@ -1,16 +1,26 @@
 //! Defines the result types for parsers.
 //! 
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-03-31
+//! Last Moddified --- 2026-10-18
 
-use core::{
-  fmt,
-  ops::Try,
-};
+use core::fmt;
+#[cfg(feature = "nightly",)]
+use core::ops::{Try, FromResidual, Residual, ControlFlow,};
 
 mod result;
+#[cfg(feature = "nightly",)]
+mod failure;
 
 pub use self::result::*;
+#[cfg(feature = "nightly",)]
+pub use self::failure::*;
+
+/// The failure of parsers which cannot fail.
+#[cfg(feature = "nightly",)]
+pub(crate) type Never = !;
+/// The failure of parsers which cannot fail.
+#[cfg(not(feature = "nightly",),)]
+pub(crate) type Never = core::convert::Infallible;
 
 /// The output of a parse.
 #[derive(PartialEq, Eq, Clone, Copy,)]
@@ -56,22 +66,61 @@ impl<T, I,> PartialEq<(T, I,),> for Parse<T, I,>
   fn eq(&self, rhs: &(T, I,),) -> bool { self.value == rhs.0 && self.unused == rhs.1 }
 }
 
+#[cfg(feature = "nightly",)]
+impl<T, I,> Parse<T, I,>
+  where T: Try, {
+  /// Replaces the unused input with `input` if the value is not an output.
+  /// 
+  /// Parsers return the input they were given when they fail or are pending, this
+  /// restores that input after a sequence of steps has short circuited.
+  #[inline]
+  pub fn backtrack(self, input: I,) -> Self {
+    match self.value.branch() {
+      ControlFlow::Continue(value,) => Parse::new(T::from_output(value,), self.unused,),
+      ControlFlow::Break(residual,) => Parse::new(T::from_residual(residual,), input,),
+    }
+  }
+}
+
+#[cfg(feature = "nightly",)]
+impl<T, I,> Parse<PResult<T, !,>, I,> {
+  /// Retypes the failure of a parse which cannot fail so it can be used as a step of
+  /// parsers with any failure type.
+  #[inline]
+  pub fn infallible<E,>(self,) -> Parse<PResult<T, E,>, I,> { self.map(|value,| value.map_fail(|never,| never,),) }
+}
+
+/// The output of a `Parse` is the output of its value paired with the unused input so
+/// `?` destructures both in one step, the residual keeps the unused input alongside the
+/// residual of the value.
+#[cfg(feature = "nightly",)]
 impl<T, I,> Try for Parse<T, I,>
   where T: Try, {
-  type Ok = Parse<T::Ok, I,>;
-  type Error = Parse<T::Error, I,>;
+  type Output = (T::Output, I,);
+  type Residual = Parse<T::Residual, I,>;
 
   #[inline]
-  fn into_result(self,) -> Result<Self::Ok, Self::Error> {
-    match self.value.into_result() {
-      Ok(v) => Ok(Parse::new(v, self.unused,)),
-      Err(e) => Err(Parse::new(e, self.unused,)),
+  fn from_output((value, unused,): Self::Output,) -> Self { Parse::new(T::from_output(value,), unused,) }
+  #[inline]
+  fn branch(self,) -> ControlFlow<Self::Residual, Self::Output> {
+    match self.value.branch() {
+      ControlFlow::Continue(value,) => ControlFlow::Continue((value, self.unused,),),
+      ControlFlow::Break(residual,) => ControlFlow::Break(Parse::new(residual, self.unused,),),
     }
   }
+}
+
+#[cfg(feature = "nightly",)]
+impl<T, R, I,> FromResidual<Parse<R, I,>> for Parse<T, I,>
+  where T: FromResidual<R>, {
   #[inline]
-  fn from_ok(ok: Self::Ok,) -> Self { ok.map(T::from_ok,) }
-  #[inline]
-  fn from_error(error: Self::Error,) -> Self { error.map(T::from_error,) }
+  fn from_residual(residual: Parse<R, I,>,) -> Self { residual.map(T::from_residual,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<R, T, I,> Residual<(T, I,)> for Parse<R, I,>
+  where R: Residual<T>, {
+  type TryType = Parse<R::TryType, I,>;
 }
 
 impl<T, I,> From<(T, I,)> for Parse<T, I,> {
@@ -91,3 +140,39 @@ impl<T, I,> From<Parse<T, I,>> for (T, I,) {
   #[inline]
   fn from(Parse { value, unused, }: Parse<T, I,>,) -> (T, I,) { (value, unused,) }
 }
+
+/// Writes a parser imperatively using `?` on the `Parse`s of each step.
+/// 
+/// `try_parse!(input, |unused| body)` applies `body` to `input` where each `?` produces
+/// the output of a step paired with its unused input and short circuits on both `Failed`
+/// and `Pending`. If `body` short circuits the unused input is backtracked to `input`.
+/// Steps which cannot fail can be retyped using [`Parse::infallible`](crate::Parse::infallible).
+/// 
+/// ```
+/// use ::parser::{*, parser::sources::{NextN, Tag,},};
+/// 
+/// #[derive(PartialEq, Debug)]
+/// struct Header { kind: u8, len: u16, }
+/// 
+/// fn header(input: &[u8]) -> Parse<PResult<Header, &[u8]>, &[u8]> {
+///   try_parse!(input, |unused| {
+///     let (_, unused) = Tag::new("HD").parse(unused)?;
+///     let (&[kind], unused) = NextN::<1>.parse(unused).infallible()?;
+///     let (&len, unused) = NextN::<2>.parse(unused).infallible()?;
+///     Parse::new(Output(Header { kind, len: u16::from_be_bytes(len) }), unused)
+///   })
+/// }
+/// 
+/// assert_eq!(header(b"HD\x01\x00\x05;"), (Output(Header { kind: 1, len: 5 }), &b";"[..]));
+/// assert_eq!(header(b"HD\x01\x00"), (Pending(1), &b"HD\x01\x00"[..]));
+/// assert_eq!(header(b"HX\x01"), (Failed(&b"H"[..]), &b"HX\x01"[..]));
+/// ```
+#[cfg(feature = "nightly",)]
+#[macro_export]
+macro_rules! try_parse {
+  ($input:expr, |$unused:pat| $body:expr $(,)?) => {{
+    let input = $input;
+
+    $crate::Parse::backtrack((|$unused| $body)(::core::clone::Clone::clone(&input),), input,)
+  }};
+}
@@ -0,0 +1,69 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::PResult::{self, *,};
+use core::convert::Infallible;
+
+/// A `Try` residual which may hold a failure value.
+/// 
+/// The combinators over failures use this to split a failure from the other residuals,
+/// such as `Pending`, which are passed through unchanged.
+pub trait Failure: Sized {
+  /// The failure value.
+  type Error;
+  /// This residual with the failure value replaced by `E`.
+  type Map<E,>: Failure<Error = E>;
+
+  /// Splits the failure value from this residual, any other residual is returned with
+  /// its failure value retyped.
+  fn into_failure<E,>(self,) -> Result<Self::Error, Self::Map<E,>>;
+  /// Constructs a residual from the failure value `error`.
+  fn from_failure(error: Self::Error,) -> Self;
+}
+
+impl<E,> Failure for PResult<!, E,> {
+  type Error = E;
+  type Map<F,> = PResult<!, F,>;
+
+  #[inline]
+  fn into_failure<F,>(self,) -> Result<Self::Error, Self::Map<F,>> {
+    match self {
+      Output(never,) => never,
+      Pending(pending,) => Err(Pending(pending,)),
+      Failed(error,) => Ok(error),
+    }
+  }
+  #[inline]
+  fn from_failure(error: Self::Error,) -> Self { Failed(error,) }
+}
+
+impl<E,> Failure for Result<Infallible, E> {
+  type Error = E;
+  type Map<F,> = Result<Infallible, F>;
+
+  #[inline]
+  fn into_failure<F,>(self,) -> Result<Self::Error, Self::Map<F,>> {
+    match self {
+      Ok(never) => match never {},
+      Err(error) => Ok(error),
+    }
+  }
+  #[inline]
+  fn from_failure(error: Self::Error,) -> Self { Err(error) }
+}
+
+/// The failure of an `Option` is `None`, mapping it produces a `Result`.
+impl Failure for Option<Infallible> {
+  type Error = ();
+  type Map<F,> = Result<Infallible, F>;
+
+  #[inline]
+  fn into_failure<F,>(self,) -> Result<Self::Error, Self::Map<F,>> {
+    match self {
+      Some(never) => match never {},
+      None => Ok(()),
+    }
+  }
+  #[inline]
+  fn from_failure(_: Self::Error,) -> Self { None }
+}
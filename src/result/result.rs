@@ -1,13 +1,17 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-04-07
+//! Last Moddified --- 2026-10-18
 
 use self::PResult::*;
 use core::{
   fmt,
-  ops::Try,
   iter::FromIterator,
   convert::TryFrom,
 };
+#[cfg(feature = "nightly",)]
+use core::{
+  ops::{Try, FromResidual, Residual, ControlFlow,},
+  convert::Infallible,
+};
 
 /// The result type of a lazy parser.
 /// 
@@ -186,8 +190,8 @@ impl<T, E,> PResult<T, E,>
   pub fn expect(self, msg: &str,) -> T {
     match self {
       Output(value,) => value,
-      Pending(pending,) => panic!("{}: {:?}", msg, Pending(pending,) as PResult<!, !,>,),
-      Failed(error,) => panic!("{}: {:?}", msg, Failed(error,) as PResult<!, E,>,),
+      Pending(pending,) => panic!("{}: {:?}", msg, Pending(pending,) as PResult<(), (),>,),
+      Failed(error,) => panic!("{}: {:?}", msg, Failed(error,) as PResult<(), E,>,),
     }
   }
 }
@@ -215,36 +219,72 @@ impl<T, E,> PResult<T, E,>
   #[track_caller]
   pub fn expect_failed(self, msg: &str,) -> E {
     match self {
-      Output(value,) => panic!("{}: {:?}", msg, Output(value,) as PResult<T, !,>,),
-      Pending(pending,) => panic!("{}: {:?}", msg, Pending(pending,) as PResult<!, !,>,),
+      Output(value,) => panic!("{}: {:?}", msg, Output(value,) as PResult<T, (),>,),
+      Pending(pending,) => panic!("{}: {:?}", msg, Pending(pending,) as PResult<(), (),>,),
       Failed(error,) => error,
     }
   }
 }
 
+/// The residual of a `PResult` is either `Pending` or `Failed`, `Pending` is never
+/// converted into a failure.
+/// 
+/// ```
+/// use ::parser::*;
+/// 
+/// fn sum(a: PResult<u32, ()>, b: PResult<u32, ()>) -> PResult<u32, ()> { Output(a? + b?) }
+/// 
+/// assert_eq!(sum(Output(1), Output(2)), Output(3));
+/// assert_eq!(sum(Output(1), Pending(2)), Pending(2));
+/// assert_eq!(sum(Failed(()), Pending(2)), Failed(()));
+/// ```
+#[cfg(feature = "nightly",)]
 impl<T, E,> Try for PResult<T, E,> {
-  type Ok = T;
-  type Error = Result<E, usize>;
+  type Output = T;
+  type Residual = PResult<!, E,>;
 
   #[inline]
-  fn into_result(self,) -> Result<Self::Ok, Self::Error> {
+  fn from_output(value: T,) -> Self { Output(value,) }
+  #[inline]
+  fn branch(self,) -> ControlFlow<Self::Residual, T> {
     match self {
-      Output(value,) => Ok(value),
-      Pending(pending,) => Err(Err(pending)),
-      Failed(error,) => Err(Ok(error)),
+      Output(value,) => ControlFlow::Continue(value,),
+      Pending(pending,) => ControlFlow::Break(Pending(pending,),),
+      Failed(error,) => ControlFlow::Break(Failed(error,),),
     }
   }
+}
+
+#[cfg(feature = "nightly",)]
+impl<T, E, F,> FromResidual<PResult<!, F,>> for PResult<T, E,>
+  where E: From<F>, {
   #[inline]
-  fn from_ok(value: Self::Ok,) -> Self { Output(value,) }
+  fn from_residual(residual: PResult<!, F,>,) -> Self {
+    match residual {
+      Output(never,) => never,
+      Pending(pending,) => Pending(pending,),
+      Failed(error,) => Failed(error.into(),),
+    }
+  }
+}
+
+#[cfg(feature = "nightly",)]
+impl<T, E, F,> FromResidual<Result<Infallible, F>> for PResult<T, E,>
+  where E: From<F>, {
   #[inline]
-  fn from_error(from: Self::Error,) -> Self {
-    match from {
-      Ok(error) => Failed(error,),
-      Err(pending) => Pending(pending,),
+  fn from_residual(residual: Result<Infallible, F>,) -> Self {
+    match residual {
+      Ok(never) => match never {},
+      Err(error) => Failed(error.into(),),
     }
   }
 }
 
+#[cfg(feature = "nightly",)]
+impl<T, E,> Residual<T> for PResult<!, E,> {
+  type TryType = PResult<T, E,>;
+}
+
 impl<T, E,> From<Result<T, E>> for PResult<T, E,> {
   #[inline]
   fn from(from: Result<T, E>,) -> Self {
@@ -258,7 +298,11 @@ impl<T, E,> From<Result<T, E>> for PResult<T, E,> {
 impl<T, E,> From<Result<T, Result<E, usize>>> for PResult<T, E,> {
   #[inline]
   fn from(from: Result<T, Result<E, usize>>,) -> Self {
-    from.map_or_else(Self::from_error, Output,)
+    match from {
+      Ok(value) => Output(value,),
+      Err(Ok(error)) => Failed(error,),
+      Err(Err(pending)) => Pending(pending,),
+    }
   }
 }
 
@@ -291,6 +335,6 @@ impl<A, V, E,> FromIterator<PResult<A, E,>> for PResult<V, E,>
   #[inline]
   fn from_iter<I,>(iter: I,) -> Self
     where I: IntoIterator<Item = PResult<A, E,>>, {
-    iter.into_iter().map(PResult::into_result,).collect::<Result<V, _>>().into()
+    iter.into_iter().map(Result::<A, Result<E, usize>>::from,).collect::<Result<V, _>>().into()
   }
 }
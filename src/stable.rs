@@ -0,0 +1,47 @@
+//! A parser trait and combinators which build on a stable toolchain.
+//! 
+//! The parsers of the [`parser`](crate::parser) module implement the `Fn*` traits which
+//! requires the `unboxed_closures` and `fn_traits` features. With
+//! `default-features = false, features = ["stable",]` the crate builds on stable, the
+//! `Always`, `Next`, `NextN`, `Tag`, `TagNoCase`, `Sat`, `Sat1` and `Eof` sources and the
+//! `Map`, `MapOk`, `MapErr`, `SeqOk`, `And`, `AndOk`, `Or` and `Backtrack` combinators
+//! then implement [`StableParse`](self::StableParse) directly and plain closures implement
+//! it through their `Fn` implementation. Other parsers are only available with the
+//! `nightly` feature.
+//! 
+//! The `nightly` feature is kept as an opt-in extra, with it the same parsers implement the
+//! `Fn*` traits instead and every `ParserFn` is a `StableParse` through its `Fn`
+//! implementation. Either way parsers can be wrapped in a [`StableParser`](self::StableParser),
+//! which is named so that it does not collide with [`Parser`](crate::Parser) when both are
+//! imported.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::*;
+
+mod parser;
+
+pub use self::parser::*;
+
+/// A trait for shared parsers which does not require the `Fn*` traits to be implemented.
+/// 
+/// A parser is a stateful computation which given some input produces a value and a new
+/// input. In the case of most parsers the input will be some sequence of tokens where
+/// some prefix is consumed to produce the output and the unused suffix is returned as
+/// the new state.
+pub trait StableParse<Input,> {
+  /// The output produced by the parser.
+  type Value;
+
+  /// Calls the parser.
+  fn parse(&self, input: Input,) -> Parse<Self::Value, Input,>;
+}
+
+impl<F, I, O,> StableParse<I,> for F
+  where F: Fn(I,) -> Parse<O, I,>, {
+  type Value = O;
+
+  #[inline]
+  fn parse(&self, input: I,) -> Parse<Self::Value, I,> { self(input,) }
+}
@@ -0,0 +1,208 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{*,
+  parser::{sources::{Always, Next, Tag, Eof, Sat,}, mapping::{Map, MapOk, MapErr,}, sequence::{Pipe, SeqOk, Backtrack,},},
+  combinators::{And, AndOk, Or, Pair, TryMap, TryMapErr, TrySeq, SeqApply, SeqApplyOk, SeqApplyErr,},
+};
+use super::StableParse;
+
+/// An adaptor for parsers which provides methods for chaining and transforming parsers
+/// on a stable toolchain.
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct StableParser<P,>(pub P,);
+
+impl<P,> StableParser<P,> {
+  /// Constructs a new `StableParser` from `parser`.
+  #[inline]
+  pub const fn new(parser: P,) -> Self { StableParser(parser,) }
+  /// Returns the inner value.
+  #[inline]
+  pub fn into_inner(self,) -> P { self.0 }
+  /// Calls the parser.
+  /// 
+  /// With the `nightly` feature `ParserFn::parse` also applies, this resolves to
+  /// `StableParse::parse` so either trait can be imported.
+  #[inline]
+  pub fn parse<I,>(&self, input: I,) -> Parse<P::Value, I,>
+    where P: StableParse<I,>, { StableParse::parse(&self.0, input,) }
+}
+
+impl<T,> StableParser<Always<T,>,> {
+  /// Constructs a new `StableParser` which always outputs `value`.
+  /// 
+  /// ```
+  /// use ::parser::{*, stable::*,};
+  /// 
+  /// let parser = StableParser::always(42);
+  /// assert_eq!(parser.parse("abc"), (42, "abc"));
+  /// ```
+  #[inline]
+  pub const fn always(value: T,) -> Self { Self::new(Always::new(value,),) }
+}
+
+impl StableParser<Next,> {
+  /// Constructs a new parser which accepts the next `count` tokens.
+  /// 
+  /// ```
+  /// use ::parser::{*, stable::*,};
+  /// 
+  /// let parser = StableParser::next(3);
+  /// assert_eq!(parser.parse(&b"abc"[..]), (Output(&b"abc"[..]), &b""[..]));
+  /// ```
+  #[inline]
+  pub const fn next(count: usize,) -> Self { Self::new(Next::new(count,),) }
+}
+
+impl<T,> StableParser<Tag<T,>,> {
+  /// Constructs a new parser which accepts a specific sequence of tokens.
+  /// 
+  /// ```
+  /// use ::parser::{*, stable::*,};
+  /// 
+  /// let parser = StableParser::tag("abc");
+  /// assert_eq!(parser.parse(&b"abcd"[..]), (Output(&b"abc"[..]), &b"d"[..]));
+  /// assert_eq!(parser.parse(&b"ab"[..]), (Pending(1), &b"ab"[..]));
+  /// ```
+  #[inline]
+  pub const fn tag(tag: T,) -> Self { Self::new(Tag::new(tag,),) }
+}
+
+impl StableParser<Eof,> {
+  /// The `Eof` parser.
+  pub const EOF: Self = StableParser(Eof,);
+}
+
+impl<F,> StableParser<Sat<F,>,> {
+  /// Constructs a new parser which accepts tokens as long as they satisfy a predicate.
+  /// 
+  /// ```
+  /// use ::parser::{*, stable::*,};
+  /// 
+  /// let parser = StableParser::sat(|_, &t: &u8| t != b'c');
+  /// assert_eq!(parser.parse(&b"abc"[..]), (Output(&b"ab"[..]), &b"c"[..]));
+  /// ```
+  #[inline]
+  pub const fn sat(pred: F,) -> Self { Self::new(Sat::new(pred,),) }
+}
+
+impl<P,> StableParser<P,> {
+  /// Maps the output of this parser using `map`.
+  /// 
+  /// ```
+  /// use ::parser::{*, stable::*,};
+  /// 
+  /// let parser = StableParser::always(21).map(|x| x * 2);
+  /// assert_eq!(parser.parse("abc"), (42, "abc"));
+  /// ```
+  #[inline]
+  pub fn map<F,>(self, map: F,) -> StableParser<Map<F, P,>,> { StableParser::new(Map::new(map, self.0,),) }
+  /// Maps the successful output of this parser using `map`.
+  /// 
+  /// ```
+  /// use ::parser::{*, stable::*,};
+  /// 
+  /// let parser = StableParser::tag("ab").map_ok(<[u8]>::len);
+  /// assert_eq!(parser.parse(&b"abc"[..]), (Output(2), &b"c"[..]));
+  /// ```
+  #[inline]
+  pub fn map_ok<F,>(self, map: F,) -> StableParser<MapOk<F, P,>,> { StableParser::new(Map::new(TryMap(map,), self.0,),) }
+  /// Maps the failure output of this parser using `map`.
+  /// 
+  /// ```
+  /// use ::parser::{*, stable::*,};
+  /// 
+  /// let parser = StableParser::tag("ab").map_err(<[u8]>::len);
+  /// assert_eq!(parser.parse(&b"ac"[..]), (Failed(1), &b"ac"[..]));
+  /// ```
+  #[inline]
+  pub fn map_err<F,>(self, map: F,) -> StableParser<MapErr<F, P,>,> { StableParser::new(Map::new(TryMapErr(map,), self.0,),) }
+  /// Maps the successful output of this parser to another parser which is applied to the
+  /// unused input.
+  /// 
+  /// ```
+  /// use ::parser::{*, stable::*,};
+  /// 
+  /// let parser = StableParser::next(1).seq_ok(|len: &[u8]| StableParser::next(len[0] as usize));
+  /// assert_eq!(parser.parse(&b"\x02abc"[..]), (Output(&b"ab"[..]), &b"c"[..]));
+  /// ```
+  #[inline]
+  pub fn seq_ok<F,>(self, map: F,) -> StableParser<SeqOk<F, P,>,> { StableParser::new(Pipe::new(TrySeq(map,), self.0,),) }
+  /// Sequences both of the parsers and returns both outputs in a tuple.
+  /// 
+  /// ```
+  /// use ::parser::{*, stable::*,};
+  /// 
+  /// let parser = StableParser::always('a').and(StableParser::always('b'));
+  /// assert_eq!(parser.parse("abc"), (('a', 'b'), "abc"));
+  /// ```
+  #[inline]
+  pub fn and<Q,>(self, parser: Q,) -> StableParser<And<P, Q,>,> {
+    StableParser::new(Pipe::new(SeqApply(parser,), Map::new(Pair, self.0,),),)
+  }
+  /// Sequences both parsers and returns the successful output of both in a tuple.
+  /// 
+  /// ```
+  /// use ::parser::{*, stable::*,};
+  /// 
+  /// let parser = StableParser::tag("a").and_ok(StableParser::tag("b"));
+  /// assert_eq!(parser.parse(&b"abc"[..]), (Output((&b"a"[..], &b"b"[..])), &b"c"[..]));
+  /// ```
+  #[inline]
+  pub fn and_ok<Q,>(self, parser: Q,) -> StableParser<AndOk<P, Q,>,> {
+    StableParser::new(Pipe::new(SeqApplyOk(parser,), Map::new(TryMap(Pair,), self.0,),),)
+  }
+  /// Returns the first successful output of either parser.
+  /// 
+  /// The second parser is applied to the input of the first so a first parser which fails
+  /// part way through does not consume any input.
+  /// 
+  /// ```
+  /// use ::parser::{*, stable::*,};
+  /// 
+  /// let parser = StableParser::tag("a").or(StableParser::tag("b"));
+  /// assert_eq!(parser.parse(&b"bc"[..]), (Output(&b"b"[..]), &b"c"[..]));
+  /// assert_eq!(parser.parse(&b"c"[..]), (Failed((&b""[..], &b""[..])), &b"c"[..]));
+  /// 
+  /// let parser = StableParser::tag("a").seq_ok(|_| StableParser::tag("b"))
+  ///   .or(StableParser::tag("ac"));
+  /// assert_eq!(parser.parse(&b"ac"[..]), (Output(&b"ac"[..]), &b""[..]));
+  /// ```
+  #[inline]
+  pub fn or<Q,>(self, parser: Q,) -> StableParser<Or<P, Q,>,> {
+    StableParser::new(Pipe::new(SeqApplyErr(parser,), Backtrack(Map::new(TryMapErr(Pair,), self.0,),),),)
+  }
+}
+
+#[cfg(feature = "nightly",)]
+impl<I, P,> FnOnce<(I,)> for StableParser<P,>
+  where P: ParserFnOnce<I,>, {
+  type Output = P::Output;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (input,): (I,),) -> Self::Output { (self.0)(input,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<I, P,> FnMut<(I,)> for StableParser<P,>
+  where P: ParserFnMut<I,>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (input,): (I,),) -> Self::Output { (self.0)(input,) }
+}
+
+#[cfg(feature = "nightly",)]
+impl<I, P,> Fn<(I,)> for StableParser<P,>
+  where P: ParserFn<I,>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (input,): (I,),) -> Self::Output { (self.0)(input,) }
+}
+
+#[cfg(not(feature = "nightly",),)]
+impl<P, I,> StableParse<I,> for StableParser<P,>
+  where P: StableParse<I,>, {
+  type Value = P::Value;
+
+  #[inline]
+  fn parse(&self, input: I,) -> Parse<Self::Value, I,> { StableParse::parse(&self.0, input,) }
+}